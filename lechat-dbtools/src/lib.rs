@@ -3,6 +3,14 @@ use sea_orm_migration::*;
 mod m_01_create_user;
 mod m_02_create_user_relationship;
 mod m_03_create_chatmsg;
+mod m_04_alter_user_password;
+mod m_05_create_user_audit;
+mod m_06_create_friend_apply;
+mod m_07_add_relationship_deleted;
+mod m_08_add_relationship_unique_pair;
+mod m_09_create_friend_team;
+mod m_10_add_user_phone_searchable;
+mod m_11_add_user_status;
 // mod utils;
 
 pub struct Migrator;
@@ -14,6 +22,14 @@ impl MigratorTrait for Migrator {
             Box::new(m_01_create_user::Migration),
             Box::new(m_02_create_user_relationship::Migration),
             Box::new(m_03_create_chatmsg::Migration),
+            Box::new(m_04_alter_user_password::Migration),
+            Box::new(m_05_create_user_audit::Migration),
+            Box::new(m_06_create_friend_apply::Migration),
+            Box::new(m_07_add_relationship_deleted::Migration),
+            Box::new(m_08_add_relationship_unique_pair::Migration),
+            Box::new(m_09_create_friend_team::Migration),
+            Box::new(m_10_add_user_phone_searchable::Migration),
+            Box::new(m_11_add_user_status::Migration),
         ]
     }
 }
@@ -0,0 +1,30 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .modify_column(
+                        ColumnDef::new(User::Password)
+                            .string()
+                            .string_len(128)
+                            .comment("用户密码哈希, Argon2id PHC格式"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// 用户表
+#[derive(Iden)]
+enum User {
+    Table,
+    Password,
+}
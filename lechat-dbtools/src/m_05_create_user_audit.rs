@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserAudit::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserAudit::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key()
+                            .comment("自增ID"),
+                    )
+                    .col(ColumnDef::new(UserAudit::UserId).big_integer().not_null().comment("用户id"))
+                    .col(
+                        ColumnDef::new(UserAudit::Action)
+                            .string()
+                            .string_len(32)
+                            .not_null()
+                            .comment("操作类型"),
+                    )
+                    .col(
+                        ColumnDef::new(UserAudit::ClientType)
+                            .string()
+                            .string_len(16)
+                            .comment("操作的设备类型"),
+                    )
+                    .col(ColumnDef::new(UserAudit::Ip).string().string_len(64).comment("操作的来源IP"))
+                    .col(
+                        ColumnDef::new(UserAudit::CreateTime)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp())
+                            .comment("创建时间"),
+                    )
+                    .index(Index::create().name("idx_user_audit_user_id").col(UserAudit::UserId))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// 用户安全操作审计表
+#[derive(Iden)]
+pub enum UserAudit {
+    Table,
+    Id,
+    UserId,
+    Action,
+    ClientType,
+    Ip,
+    CreateTime,
+}
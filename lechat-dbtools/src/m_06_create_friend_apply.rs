@@ -0,0 +1,90 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FriendApply::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FriendApply::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key()
+                            .comment("自增ID"),
+                    )
+                    .col(
+                        ColumnDef::new(FriendApply::FromUserId)
+                            .big_integer()
+                            .not_null()
+                            .comment("申请人id"),
+                    )
+                    .col(
+                        ColumnDef::new(FriendApply::ToUserId)
+                            .big_integer()
+                            .not_null()
+                            .comment("被申请人id"),
+                    )
+                    .col(
+                        ColumnDef::new(FriendApply::Message)
+                            .string()
+                            .string_len(128)
+                            .comment("验证消息"),
+                    )
+                    .col(
+                        ColumnDef::new(FriendApply::Status)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("处理结果: 0 待处理, 1 拒绝, 2 接受"),
+                    )
+                    .col(
+                        ColumnDef::new(FriendApply::CreateTime)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp())
+                            .comment("申请时间"),
+                    )
+                    .col(
+                        ColumnDef::new(FriendApply::UpdateTime)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp())
+                            .comment("处理时间"),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_friend_apply_to_user_status")
+                            .col(FriendApply::ToUserId)
+                            .col(FriendApply::Status),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_friend_apply_from_user_to_user")
+                            .col(FriendApply::FromUserId)
+                            .col(FriendApply::ToUserId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// 好友申请表
+#[derive(Iden)]
+pub enum FriendApply {
+    Table,
+    Id,
+    FromUserId,
+    ToUserId,
+    Message,
+    Status,
+    CreateTime,
+    UpdateTime,
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UserRelationShip::Table)
+                    .add_column(
+                        ColumnDef::new(UserRelationShip::Deleted)
+                            .boolean()
+                            .not_null()
+                            .default(false)
+                            .comment("是否已解除好友关系, 保留记录用于好友列表增量同步"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// 用户关系表
+#[derive(Iden)]
+enum UserRelationShip {
+    Table,
+    Deleted,
+}
@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 同一对用户只保留一条记录, 优先保留未解除的, 其次保留最早的
+const DEDUPLICATE_SQL: &str = r#"
+DELETE r1 FROM user_relation_ship r1
+JOIN user_relation_ship r2
+  ON LEAST(r1.user_id1, r1.user_id2) = LEAST(r2.user_id1, r2.user_id2)
+ AND GREATEST(r1.user_id1, r1.user_id2) = GREATEST(r2.user_id1, r2.user_id2)
+ AND (r1.deleted > r2.deleted OR (r1.deleted = r2.deleted AND r1.id > r2.id))
+"#;
+
+/// 交换`user_id1`大于`user_id2`的记录, 分组及备注随用户一起交换.
+/// MySQL按顺序执行SET, 原值需从派生表读取
+const NORMALIZE_SQL: &str = r#"
+UPDATE user_relation_ship r
+JOIN (
+    SELECT id, user_id1, user_id2, user1_group_name, user1_mark_name, user2_group_name, user2_mark_name
+    FROM user_relation_ship
+    WHERE user_id1 > user_id2
+) o ON r.id = o.id
+SET r.user_id1 = o.user_id2,
+    r.user_id2 = o.user_id1,
+    r.user1_group_name = o.user2_group_name,
+    r.user1_mark_name = o.user2_mark_name,
+    r.user2_group_name = o.user1_group_name,
+    r.user2_mark_name = o.user1_mark_name
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(DEDUPLICATE_SQL).await?;
+        db.execute_unprepared(NORMALIZE_SQL).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_user_relation_ship_pair")
+                    .table(UserRelationShip::Table)
+                    .col(UserRelationShip::UserId1)
+                    .col(UserRelationShip::UserId2)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_relation_ship_user_id2")
+                    .table(UserRelationShip::Table)
+                    .col(UserRelationShip::UserId2)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// 用户关系表, 约定`user_id1`小于`user_id2`
+#[derive(Iden)]
+enum UserRelationShip {
+    Table,
+    UserId1,
+    UserId2,
+}
@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 好友关系中已使用的分组补充到分组表
+const IMPORT_TEAMS_SQL: &str = r#"
+INSERT IGNORE INTO friend_team (user_id, name)
+SELECT user_id1, user1_group_name FROM user_relation_ship WHERE user1_group_name <> '我的好友'
+UNION
+SELECT user_id2, user2_group_name FROM user_relation_ship WHERE user2_group_name <> '我的好友'
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FriendTeam::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FriendTeam::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key()
+                            .comment("自增ID"),
+                    )
+                    .col(ColumnDef::new(FriendTeam::UserId).big_integer().not_null().comment("用户id"))
+                    .col(
+                        ColumnDef::new(FriendTeam::Name)
+                            .string()
+                            .string_len(32)
+                            .not_null()
+                            .comment("分组名称, 默认分组\"我的好友\"不在此表中"),
+                    )
+                    .col(
+                        ColumnDef::new(FriendTeam::Sort)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .comment("排序, 从小到大"),
+                    )
+                    .col(
+                        ColumnDef::new(FriendTeam::CreateTime)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp())
+                            .comment("创建时间"),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uk_friend_team_user_name")
+                            .col(FriendTeam::UserId)
+                            .col(FriendTeam::Name)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager.get_connection().execute_unprepared(IMPORT_TEAMS_SQL).await?;
+
+        // 分组信息改由分组表保存
+        manager
+            .alter_table(Table::alter().table(User::Table).drop_column(User::GropupInfo).to_owned())
+            .await
+    }
+}
+
+/// 好友分组表
+#[derive(Iden)]
+pub enum FriendTeam {
+    Table,
+    Id,
+    UserId,
+    Name,
+    Sort,
+    CreateTime,
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    GropupInfo,
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(
+                        ColumnDef::new(User::PhoneSearchable)
+                            .boolean()
+                            .not_null()
+                            .default(true)
+                            .comment("是否允许通过手机号搜索到"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// 用户表
+#[derive(Iden)]
+enum User {
+    Table,
+    PhoneSearchable,
+}
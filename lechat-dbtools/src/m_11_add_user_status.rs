@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(
+                        ColumnDef::new(User::Status)
+                            .string()
+                            .string_len(32)
+                            .not_null()
+                            .default("active")
+                            .comment("账号状态: pending_verification 待验证, active 正常"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_status_register_time")
+                    .table(User::Table)
+                    .col(User::Status)
+                    .col(User::RegisterTime)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// 用户表
+#[derive(Iden)]
+enum User {
+    Table,
+    Status,
+    RegisterTime,
+}
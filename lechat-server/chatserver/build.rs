@@ -6,7 +6,9 @@ fn main() {
         .out_dir("src/network/stubs")
         .include("src/network/protos")
         .inputs([
+            "src/network/protos/chat.proto",
             "src/network/protos/chatmsg.proto",
+            "src/network/protos/friend.proto",
             "src/network/protos/user.proto"
         ])
         .run_from_script();
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -13,6 +14,30 @@ pub struct Config {
     pub log: Log,
     pub database: DatabaseConfig,
     pub redis: RedisConfig,
+    #[serde(default)]
+    pub codec: CodecConfig,
+    #[serde(default)]
+    pub heartbeat: HeartbeatConfig,
+    #[serde(default)]
+    pub retransmit: RetransmitConfig,
+    #[serde(default)]
+    pub protocol: ProtocolConfig,
+    #[serde(default)]
+    pub password: PasswordConfig,
+    #[serde(default)]
+    pub token: TokenConfig,
+    #[serde(default)]
+    pub sms: SmsConfig,
+    #[serde(default)]
+    pub login_guard: LoginGuardConfig,
+    #[serde(default)]
+    pub mail: MailConfig,
+    #[serde(default)]
+    pub password_reset: PasswordResetConfig,
+    #[serde(default)]
+    pub verification: VerificationConfig,
+    #[serde(default)]
+    pub chat: ChatConfig,
 }
 
 #[derive(Debug, Deserialize)]
@@ -21,6 +46,20 @@ pub struct Addr {
     pub port: u16,
     #[serde(default)]
     pub token: Option<String>,
+    /// 配置后该监听地址启用TLS
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TlsConfig {
+    /// PEM格式的证书链
+    pub cert_path: PathBuf,
+    /// PEM格式的私钥
+    pub key_path: PathBuf,
+    /// 配置后要求客户端提供由该CA签发的证书
+    #[serde(default)]
+    pub client_ca_path: Option<PathBuf>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,6 +92,186 @@ pub struct RedisConfig {
     pub password: Option<String>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct CodecConfig {
+    pub max_frame_size: Option<usize>,
+    pub compress_threshold: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct HeartbeatConfig {
+    /// 检查空闲连接的间隔
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub interval: Option<Duration>,
+    /// 连接空闲超过该时间后关闭
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RetransmitConfig {
+    /// 推送未确认时首次重发的间隔, 之后每次翻倍
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub interval: Option<Duration>,
+    /// 最多发送次数, 仍未确认则转存离线
+    pub max_attempts: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ProtocolConfig {
+    /// 支持的最低协议版本
+    pub min_version: Option<u32>,
+    /// 支持的最高协议版本
+    pub max_version: Option<u32>,
+    /// 按设备类型覆盖最低协议版本, 键为小写的`ClientType`名称, 如android
+    #[serde(default)]
+    pub client_min_version: HashMap<String, u32>,
+    /// 版本过低时提示的升级地址
+    pub upgrade_url: Option<String>,
+}
+
+/// Argon2id哈希参数, 未配置时使用argon2的默认值
+#[derive(Debug, Default, Deserialize)]
+pub struct PasswordConfig {
+    /// 内存开销(KiB)
+    pub memory_cost: Option<u32>,
+    /// 迭代次数
+    pub time_cost: Option<u32>,
+    /// 并行度
+    pub parallelism: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TokenConfig {
+    /// 访问令牌有效期
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub access_ttl: Option<Duration>,
+    /// 刷新令牌有效期
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub refresh_ttl: Option<Duration>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SmsConfig {
+    /// 验证码有效期
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub code_ttl: Option<Duration>,
+    /// 同一手机号两次发送的最小间隔
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub resend_interval: Option<Duration>,
+    /// 同一手机号每小时最多发送次数
+    pub phone_hourly_limit: Option<u32>,
+    /// 同一IP每小时最多发送次数
+    pub ip_hourly_limit: Option<u32>,
+    /// 每个验证码最多校验次数, 超过后失效
+    pub max_attempts: Option<u32>,
+    /// 配置后验证码写入该文件而不是日志, 用于本地测试
+    pub outbox_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct LoginGuardConfig {
+    /// 登录失败次数的统计窗口
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub window: Option<Duration>,
+    /// 同一用户名在窗口内失败多少次后锁定
+    pub user_threshold: Option<u32>,
+    /// 同一IP在窗口内失败多少次后锁定
+    pub ip_threshold: Option<u32>,
+    /// 锁定时长
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub lock_duration: Option<Duration>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MailConfig {
+    /// 发件人, 如`LeChat <noreply@lechat.example.com>`
+    pub from: Option<String>,
+    /// 配置后通过SMTP发送邮件
+    pub smtp: Option<SmtpConfig>,
+    /// 未配置SMTP时邮件写入该文件而不是日志, 用于本地测试
+    pub outbox_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    /// 不配置时使用加密方式对应的默认端口
+    pub port: Option<u16>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// 直接建立TLS连接, 默认端口465
+    Tls,
+    /// 明文连接后升级为TLS, 默认端口587
+    #[default]
+    Starttls,
+    /// 不加密, 仅用于本地测试
+    None,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct PasswordResetConfig {
+    /// 重置令牌有效期
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub token_ttl: Option<Duration>,
+    /// 同一邮箱两次申请的最小间隔
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub resend_interval: Option<Duration>,
+    /// 重置页面地址, 令牌以`token`参数附加在后面
+    pub reset_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct VerificationConfig {
+    /// 注册后多久内未验证的账号被删除, 同时也是验证链接的有效期
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub ttl: Option<Duration>,
+    /// 清理未验证账号的间隔
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub cleanup_interval: Option<Duration>,
+    /// 验证页面地址, 令牌以`token`参数附加在后面
+    pub verify_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ChatConfig {
+    /// 非好友之间单聊消息的处理方式
+    #[serde(default)]
+    pub stranger_policy: StrangerChatPolicy,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StrangerChatPolicy {
+    /// 正常投递
+    Allow,
+    /// 不投递, 告知发送方对方不是好友
+    #[default]
+    Reject,
+    /// 不投递, 发送方仍收到成功应答
+    Drop,
+}
+
 pub fn init_config<P: AsRef<Path>>(cfg_path: P) -> Result<Config> {
     let cfg = config::Config::builder()
         .add_source(config::File::from(cfg_path.as_ref()))
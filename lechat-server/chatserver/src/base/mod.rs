@@ -2,5 +2,6 @@ pub mod app_state;
 pub mod config;
 pub mod log;
 pub mod singleton;
+pub mod tls;
 pub mod transaction;
 pub mod response;
@@ -89,8 +89,32 @@ pub enum Error {
     UserNotRegistered,
     #[error("username is duplicate")]
     UsernameDuplicate,
+    #[error("phone is duplicate")]
+    PhoneDuplicate,
+    #[error("email is duplicate")]
+    EmailDuplicate,
     #[error("username or password mismatch")]
     UserNameOrPasswordMismatch,
+    #[error("password mismatch")]
+    PasswordMismatch,
+    #[error("user is not login")]
+    NotLogin,
+    #[error("token is invalid or expired")]
+    TokenInvalid,
+    #[error("verification code is invalid or expired")]
+    VerifyCodeInvalid,
+    #[error("too many requests, please try again later")]
+    TooManyRequests,
+    #[error("account is temporarily locked, please try again in {0} seconds")]
+    AccountLocked(u64),
+    #[error("account is not verified, please verify your email or phone first")]
+    AccountNotVerified,
+    #[error("you are already friends")]
+    AlreadyFriend,
+    #[error("you are not friends")]
+    NotFriend,
+    #[error("team name already exists")]
+    TeamNameDuplicate,
 }
 
 impl Error {
@@ -101,6 +125,18 @@ impl Error {
             Error::UserNotRegistered => 1002,
             Error::UsernameDuplicate => 1003,
             Error::UserNameOrPasswordMismatch => 1004,
+            Error::NotLogin => 1005,
+            Error::TokenInvalid => 1006,
+            Error::VerifyCodeInvalid => 1007,
+            Error::TooManyRequests => 1008,
+            Error::PhoneDuplicate => 1009,
+            Error::EmailDuplicate => 1010,
+            Error::PasswordMismatch => 1011,
+            Error::AccountLocked(_) => 1012,
+            Error::AccountNotVerified => 1013,
+            Error::AlreadyFriend => 1014,
+            Error::NotFriend => 1015,
+            Error::TeamNameDuplicate => 1016,
         }
     }
 }
@@ -109,10 +145,19 @@ impl ResponseError for Error {
     fn status_code(&self) -> StatusCode {
         match self {
             Error::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
-            Error::UserNotRegistered => StatusCode::UNAUTHORIZED,
-            Error::ParamInvalid(_) | Error::UsernameDuplicate | Error::UserNameOrPasswordMismatch => {
-                StatusCode::BAD_REQUEST
-            }
+            Error::UserNotRegistered | Error::NotLogin | Error::TokenInvalid => StatusCode::UNAUTHORIZED,
+            Error::ParamInvalid(_)
+            | Error::UsernameDuplicate
+            | Error::PhoneDuplicate
+            | Error::EmailDuplicate
+            | Error::UserNameOrPasswordMismatch
+            | Error::PasswordMismatch
+            | Error::VerifyCodeInvalid
+            | Error::AlreadyFriend
+            | Error::TeamNameDuplicate => StatusCode::BAD_REQUEST,
+            Error::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            Error::AccountLocked(_) => StatusCode::LOCKED,
+            Error::AccountNotVerified | Error::NotFriend => StatusCode::FORBIDDEN,
         }
     }
 
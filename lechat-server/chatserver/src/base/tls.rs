@@ -0,0 +1,59 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::Arc;
+
+use rustls::crypto::ring;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+
+use crate::base::config::TlsConfig;
+use crate::{Error, Result};
+
+/// 根据配置构建rustls服务端配置, 配置了客户端CA时启用双向认证
+pub fn server_config(cfg: &TlsConfig) -> Result<ServerConfig> {
+    let certs = load_certs(&cfg.cert_path)?;
+    let key = load_private_key(&cfg.key_path)?;
+
+    let provider = Arc::new(ring::default_provider());
+    let builder = ServerConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|e| Error::ConfigError(e.to_string()))?;
+    let builder = match &cfg.client_ca_path {
+        Some(path) => {
+            let mut roots = RootCertStore::empty();
+            for cert in load_certs(path)? {
+                roots.add(cert).map_err(|e| tls_error(path, e))?;
+            }
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .map_err(|e| tls_error(path, e))?;
+            builder.with_client_cert_verifier(verifier)
+        }
+        None => builder.with_no_client_auth(),
+    };
+    builder.with_single_cert(certs, key).map_err(|e| tls_error(&cfg.cert_path, e))
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    let mut reader = BufReader::new(File::open(path).map_err(|e| tls_error(path, e))?);
+    let certs = rustls_pemfile::certs(&mut reader)
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| tls_error(path, e))?;
+    if certs.is_empty() {
+        return Err(tls_error(path, "no certificate found"));
+    }
+    Ok(certs)
+}
+
+fn load_private_key(path: &Path) -> Result<PrivateKeyDer<'static>> {
+    let mut reader = BufReader::new(File::open(path).map_err(|e| tls_error(path, e))?);
+    rustls_pemfile::private_key(&mut reader)
+        .map_err(|e| tls_error(path, e))?
+        .ok_or_else(|| tls_error(path, "no private key found"))
+}
+
+fn tls_error(path: &Path, err: impl std::fmt::Display) -> Error {
+    Error::ConfigError(format!("invalid tls file {}, {}", path.display(), err))
+}
@@ -4,11 +4,36 @@ use fred::prelude::RedisClient;
 use once_cell::sync::OnceCell;
 use sea_orm::{DatabaseConnection, DatabaseTransaction};
 
+use crate::base::config::Config;
 use crate::components::mysql::{MysqlServiceImpl, MysqlServiceImplParameters};
 use crate::components::redis::{RedisServiceImpl, RedisServiceImplParameters};
+use crate::db::repository::audit::AuditRepositoryImpl;
+use crate::db::repository::friend_apply::FriendApplyRepositoryImpl;
+use crate::db::repository::friend_team::FriendTeamRepositoryImpl;
+use crate::db::repository::relationship::RelationshipRepositoryImpl;
 use crate::db::repository::user::UserRepositoryImpl;
-use crate::service::checker::CheckServiceImpl;
+use crate::network::delivery::DeliveryServiceImpl;
+use crate::network::dispatcher::CommandDispatcherImpl;
+use crate::network::handler::{
+    AckHandlerImpl, ChatHandlerImpl, FindUserHandlerImpl, GetFriendListHandlerImpl, HandshakeHandlerImpl,
+    HandshakeHandlerImplParameters, HeartbeatHandlerImpl, LoginHandlerImpl, ModifyPasswordHandlerImpl,
+    OperateFriendHandlerImpl, UpdateTeamInfoHandlerImpl, UpdateUserInfoHandlerImpl,
+};
+use crate::network::registry::{SessionRegistryImpl, SessionRegistryImplParameters};
+use crate::network::session::SessionManagerImpl;
+use crate::service::audit::AuditServiceImpl;
+use crate::service::friend::{FriendServiceImpl, FriendServiceImplParameters};
+use crate::service::friend_team::FriendTeamServiceImpl;
+use crate::service::login_guard::{LoginGuardServiceImpl, LoginGuardServiceImplParameters};
+use crate::service::mail;
+use crate::service::password::{PasswordServiceImpl, PasswordServiceImplParameters};
+use crate::service::password_reset::{PasswordResetServiceImpl, PasswordResetServiceImplParameters};
+use crate::service::presence::PresenceServiceImpl;
+use crate::service::reservation::ReservationServiceImpl;
+use crate::service::sms::{self, SmsCodeServiceImpl, SmsCodeServiceImplParameters};
+use crate::service::token::{TokenServiceImpl, TokenServiceImplParameters};
 use crate::service::user::UserServiceImpl;
+use crate::service::verification::{VerificationServiceImpl, VerificationServiceImplParameters};
 
 pub mod mysql;
 pub mod redis;
@@ -21,11 +46,42 @@ shaku::module! {
             // basic components
             RedisServiceImpl,
             MysqlServiceImpl,
+            SessionManagerImpl,
+            SessionRegistryImpl,
+            DeliveryServiceImpl,
 
             // biz components
             UserRepositoryImpl,
+            RelationshipRepositoryImpl,
+            AuditRepositoryImpl,
+            FriendApplyRepositoryImpl,
+            FriendTeamRepositoryImpl,
             UserServiceImpl,
-            CheckServiceImpl,
+            ReservationServiceImpl,
+            PasswordServiceImpl,
+            TokenServiceImpl,
+            PresenceServiceImpl,
+            AuditServiceImpl,
+            SmsCodeServiceImpl,
+            LoginGuardServiceImpl,
+            PasswordResetServiceImpl,
+            VerificationServiceImpl,
+            FriendServiceImpl,
+            FriendTeamServiceImpl,
+
+            // long connection command handlers
+            CommandDispatcherImpl,
+            HandshakeHandlerImpl,
+            HeartbeatHandlerImpl,
+            LoginHandlerImpl,
+            AckHandlerImpl,
+            ChatHandlerImpl,
+            ModifyPasswordHandlerImpl,
+            UpdateUserInfoHandlerImpl,
+            FindUserHandlerImpl,
+            OperateFriendHandlerImpl,
+            GetFriendListHandlerImpl,
+            UpdateTeamInfoHandlerImpl,
         ],
         providers = []
     }
@@ -33,13 +89,38 @@ shaku::module! {
 
 /// 注册服务组件
 pub async fn register_components(
+    cfg: Arc<Config>,
     db_conn: Arc<DatabaseConnection>,
     db_tx: Arc<DatabaseTransaction>,
     redis_cli: Arc<RedisClient>,
 ) -> anyhow::Result<Arc<Modules>> {
+    // 实例标识, 用于多实例间定位会话所在的实例
+    let instance_id = uuid::Uuid::new_v4().simple().to_string();
+    tracing::info!("lechat-server instance id: {}", instance_id);
+    let mailer = mail::mailer(&cfg.mail)?;
     let modules = Modules::builder()
         .with_component_parameters::<MysqlServiceImpl>(MysqlServiceImplParameters { db_conn, db_tx })
         .with_component_parameters::<RedisServiceImpl>(RedisServiceImplParameters { redis_cli })
+        .with_component_parameters::<SessionRegistryImpl>(SessionRegistryImplParameters { instance_id })
+        .with_component_parameters::<PasswordServiceImpl>(PasswordServiceImplParameters { cfg: cfg.clone() })
+        .with_component_parameters::<TokenServiceImpl>(TokenServiceImplParameters { cfg: cfg.clone() })
+        .with_component_parameters::<SmsCodeServiceImpl>(SmsCodeServiceImplParameters {
+            sender: sms::sms_sender(&cfg.sms),
+            cfg: cfg.clone(),
+        })
+        .with_component_parameters::<LoginGuardServiceImpl>(LoginGuardServiceImplParameters {
+            cfg: cfg.clone(),
+        })
+        .with_component_parameters::<PasswordResetServiceImpl>(PasswordResetServiceImplParameters {
+            mailer: mailer.clone(),
+            cfg: cfg.clone(),
+        })
+        .with_component_parameters::<VerificationServiceImpl>(VerificationServiceImplParameters {
+            mailer,
+            cfg: cfg.clone(),
+        })
+        .with_component_parameters::<FriendServiceImpl>(FriendServiceImplParameters { cfg: cfg.clone() })
+        .with_component_parameters::<HandshakeHandlerImpl>(HandshakeHandlerImplParameters { cfg })
         .build();
 
     let res = Arc::new(modules);
@@ -34,7 +34,9 @@ pub trait IMysqlService: Interface {
 #[derive(Component)]
 #[shaku(interface = IMysqlService)]
 pub struct MysqlServiceImpl {
+    #[shaku(no_default)]
     db_conn: Arc<DatabaseConnection>,
+    #[shaku(no_default)]
     db_tx: Arc<DatabaseTransaction>,
 }
 
@@ -1,7 +1,9 @@
+use std::future::Future;
 use std::sync::Arc;
 use fred::prelude::*;
 use fred::types::RespVersion;
 use shaku::{Component, Interface};
+use tokio::sync::broadcast::error::RecvError;
 
 use crate::base::config::RedisConfig;
 
@@ -35,7 +37,6 @@ pub async fn init_redis(redis_cfg: &RedisConfig) -> Result<RedisClient, RedisErr
         }
     });
     client.init().await?;
-    client.quit().await?;
 
     tracing::info!("Init redis client successfully!");
     Ok(client)
@@ -48,6 +49,7 @@ pub trait IRedisService: Interface {
 #[derive(Component)]
 #[shaku(interface = IRedisService)]
 pub struct RedisServiceImpl {
+    #[shaku(no_default)]
     redis_cli: Arc<RedisClient>,
 }
 
@@ -56,3 +58,43 @@ impl IRedisService for RedisServiceImpl {
         self.redis_cli.clone()
     }
 }
+
+/// 订阅频道并逐条处理消息, 断线重连后重新订阅.
+/// 消息按顺序处理以保证同一用户的推送有序, 处理不及时积压的消息会被丢弃
+pub async fn subscribe<F, Fut>(redis_cli: Arc<RedisClient>, channel: String, handle: F)
+where
+    F: Fn(RedisValue) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let subscriber = redis_cli.clone_new();
+    let mut message_rx = subscriber.message_rx();
+    let mut reconnect_rx = subscriber.reconnect_rx();
+    if let Err(err) = subscriber.init().await {
+        tracing::error!("init subscriber of {} failed, {err:#}", channel);
+        return;
+    }
+    if let Err(err) = subscriber.subscribe(channel.as_str()).await {
+        tracing::error!("subscribe {} failed, {err:#}", channel);
+        return;
+    }
+    tracing::info!("subscribed to {}", channel);
+
+    loop {
+        tokio::select! {
+            message = message_rx.recv() => match message {
+                Ok(message) => handle(message.value).await,
+                Err(RecvError::Lagged(n)) => tracing::warn!("subscriber of {} lagged, {} messages dropped", channel, n),
+                Err(RecvError::Closed) => break,
+            },
+            server = reconnect_rx.recv() => match server {
+                Ok(_) | Err(RecvError::Lagged(_)) => {
+                    if let Err(err) = subscriber.subscribe(channel.as_str()).await {
+                        tracing::error!("resubscribe {} failed, {err:#}", channel);
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+    tracing::warn!("subscriber of {} stopped", channel);
+}
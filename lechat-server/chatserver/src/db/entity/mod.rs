@@ -3,5 +3,9 @@
 pub mod prelude;
 
 pub mod chat_msg;
+pub mod friend_apply;
+pub mod friend_team;
+pub mod sea_orm_active_enums;
 pub mod user;
+pub mod user_audit;
 pub mod user_relation_ship;
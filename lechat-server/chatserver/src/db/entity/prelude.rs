@@ -1,5 +1,8 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.14
 
 pub use super::chat_msg::Entity as ChatMsg;
+pub use super::friend_apply::Entity as FriendApply;
+pub use super::friend_team::Entity as FriendTeam;
 pub use super::user::Entity as User;
+pub use super::user_audit::Entity as UserAudit;
 pub use super::user_relation_ship::Entity as UserRelationShip;
@@ -2,6 +2,8 @@
 
 use sea_orm::entity::prelude::*;
 
+use super::sea_orm_active_enums::UserStatus;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "user")]
 pub struct Model {
@@ -21,8 +23,8 @@ pub struct Model {
     pub facetype: Option<i32>,
     pub customface: Option<String>,
     pub customfacefmt: Option<String>,
-    #[sea_orm(column_type = "Binary(BlobSize::Blob(None))", nullable)]
-    pub gropup_info: Option<Vec<u8>>,
+    pub phone_searchable: bool,
+    pub status: UserStatus,
     pub register_time: DateTime,
 }
 
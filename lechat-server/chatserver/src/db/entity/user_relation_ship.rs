@@ -14,6 +14,7 @@ pub struct Model {
     pub user2_group_name: String,
     pub user2_mark_name: Option<String>,
     pub update_time: DateTimeUtc,
+    pub deleted: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, DbErr};
+use shaku::{Component, Interface};
+
+use crate::components::mysql::IMysqlService;
+use crate::db::entity::user_audit::{ActiveModel, Model};
+
+#[async_trait]
+pub trait IAuditRepository: Interface {
+    async fn add(&self, audit: ActiveModel) -> Result<Model, DbErr>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IAuditRepository)]
+pub struct AuditRepositoryImpl {
+    #[shaku(inject)]
+    db_conn: Arc<dyn IMysqlService>,
+}
+
+#[async_trait]
+impl IAuditRepository for AuditRepositoryImpl {
+    async fn add(&self, audit: ActiveModel) -> Result<Model, DbErr> {
+        audit.insert(self.db_conn.get_conn().as_ref()).await
+    }
+}
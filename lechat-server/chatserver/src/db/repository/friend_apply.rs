@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::prelude::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    TransactionTrait,
+};
+use shaku::{Component, Interface};
+
+use crate::components::mysql::IMysqlService;
+use crate::db::entity::friend_apply as entity;
+use crate::db::entity::friend_apply::{ActiveModel, Model};
+use crate::db::repository::relationship;
+
+/// 待处理的申请, 对应`FriendOperationApplyType::APPLY_UNKNOWN`
+pub const STATUS_PENDING: i32 = 0;
+
+#[async_trait]
+pub trait IFriendApplyRepository: Interface {
+    async fn find_by_id(&self, id: i64) -> Result<Option<Model>, DbErr>;
+    /// 查询申请人对被申请人待处理的申请
+    async fn find_pending(&self, from: i64, to: i64) -> Result<Option<Model>, DbErr>;
+    /// 用户收到及发出的申请, 按申请时间倒序
+    async fn find_by_user(&self, id: i64, limit: u64) -> Result<Vec<Model>, DbErr>;
+    async fn add(&self, apply: ActiveModel) -> Result<Model, DbErr>;
+    async fn update(&self, apply: ActiveModel) -> Result<Model, DbErr>;
+    /// 处理待处理的申请, 接受时在同一事务中写入`friends`两人的好友关系. 申请已被处理过时返回false
+    async fn answer(&self, id: i64, status: i32, friends: Option<(i64, i64)>) -> Result<bool, DbErr>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IFriendApplyRepository)]
+pub struct FriendApplyRepositoryImpl {
+    #[shaku(inject)]
+    db_conn: Arc<dyn IMysqlService>,
+}
+
+#[async_trait]
+impl IFriendApplyRepository for FriendApplyRepositoryImpl {
+    async fn find_by_id(&self, id: i64) -> Result<Option<Model>, DbErr> {
+        entity::Entity::find_by_id(id).one(self.db_conn.get_conn().as_ref()).await
+    }
+
+    async fn find_pending(&self, from: i64, to: i64) -> Result<Option<Model>, DbErr> {
+        entity::Entity::find()
+            .filter(entity::Column::FromUserId.eq(from))
+            .filter(entity::Column::ToUserId.eq(to))
+            .filter(entity::Column::Status.eq(STATUS_PENDING))
+            .one(self.db_conn.get_conn().as_ref())
+            .await
+    }
+
+    async fn find_by_user(&self, id: i64, limit: u64) -> Result<Vec<Model>, DbErr> {
+        entity::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(entity::Column::FromUserId.eq(id))
+                    .add(entity::Column::ToUserId.eq(id)),
+            )
+            .order_by_desc(entity::Column::CreateTime)
+            .limit(limit)
+            .all(self.db_conn.get_conn().as_ref())
+            .await
+    }
+
+    async fn add(&self, apply: ActiveModel) -> Result<Model, DbErr> {
+        apply.insert(self.db_conn.get_conn().as_ref()).await
+    }
+
+    async fn update(&self, apply: ActiveModel) -> Result<Model, DbErr> {
+        apply.update(self.db_conn.get_conn().as_ref()).await
+    }
+
+    async fn answer(&self, id: i64, status: i32, friends: Option<(i64, i64)>) -> Result<bool, DbErr> {
+        let txn = self.db_conn.get_conn().begin().await?;
+        // 只更新仍待处理的申请, 并发应答时只有一个成功
+        let res = entity::Entity::update_many()
+            .col_expr(entity::Column::Status, Expr::value(status))
+            .col_expr(entity::Column::UpdateTime, Expr::value(chrono::Utc::now()))
+            .filter(entity::Column::Id.eq(id))
+            .filter(entity::Column::Status.eq(STATUS_PENDING))
+            .exec(&txn)
+            .await?;
+        if res.rows_affected == 0 {
+            return Ok(false);
+        }
+        if let Some((id1, id2)) = friends {
+            relationship::befriend(&txn, id1, id2).await?;
+        }
+        txn.commit().await?;
+        Ok(true)
+    }
+}
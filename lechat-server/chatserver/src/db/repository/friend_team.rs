@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::prelude::Expr;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, TransactionTrait};
+use shaku::{Component, Interface};
+
+use crate::components::mysql::IMysqlService;
+use crate::db::entity::friend_team as entity;
+use crate::db::entity::friend_team::{ActiveModel, Model};
+use crate::db::repository::relationship::{self, DEFAULT_GROUP_NAME};
+
+#[async_trait]
+pub trait IFriendTeamRepository: Interface {
+    /// 用户的全部分组, 按顺序排列, 不包含默认分组
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<Model>, DbErr>;
+    async fn add(&self, team: ActiveModel) -> Result<Model, DbErr>;
+    /// 重命名分组, 分组下的好友一起修改. 分组不存在时返回false
+    async fn rename(&self, user_id: i64, name: &str, new_name: &str) -> Result<bool, DbErr>;
+    /// 删除分组, 分组下的好友移到默认分组. 分组不存在时返回false
+    async fn delete(&self, user_id: i64, name: &str) -> Result<bool, DbErr>;
+    /// 按`names`的顺序重新排序
+    async fn reorder(&self, user_id: i64, names: &[String]) -> Result<(), DbErr>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IFriendTeamRepository)]
+pub struct FriendTeamRepositoryImpl {
+    #[shaku(inject)]
+    db_conn: Arc<dyn IMysqlService>,
+}
+
+#[async_trait]
+impl IFriendTeamRepository for FriendTeamRepositoryImpl {
+    async fn find_by_user(&self, user_id: i64) -> Result<Vec<Model>, DbErr> {
+        entity::Entity::find()
+            .filter(entity::Column::UserId.eq(user_id))
+            .order_by_asc(entity::Column::Sort)
+            .order_by_asc(entity::Column::Id)
+            .all(self.db_conn.get_conn().as_ref())
+            .await
+    }
+
+    async fn add(&self, team: ActiveModel) -> Result<Model, DbErr> {
+        team.insert(self.db_conn.get_conn().as_ref()).await
+    }
+
+    async fn rename(&self, user_id: i64, name: &str, new_name: &str) -> Result<bool, DbErr> {
+        let txn = self.db_conn.get_conn().begin().await?;
+        let res = entity::Entity::update_many()
+            .col_expr(entity::Column::Name, Expr::value(new_name))
+            .filter(entity::Column::UserId.eq(user_id))
+            .filter(entity::Column::Name.eq(name))
+            .exec(&txn)
+            .await?;
+        if res.rows_affected == 0 {
+            return Ok(false);
+        }
+        relationship::move_group(&txn, user_id, name, new_name).await?;
+        txn.commit().await?;
+        Ok(true)
+    }
+
+    async fn delete(&self, user_id: i64, name: &str) -> Result<bool, DbErr> {
+        let txn = self.db_conn.get_conn().begin().await?;
+        let res = entity::Entity::delete_many()
+            .filter(entity::Column::UserId.eq(user_id))
+            .filter(entity::Column::Name.eq(name))
+            .exec(&txn)
+            .await?;
+        if res.rows_affected == 0 {
+            return Ok(false);
+        }
+        relationship::move_group(&txn, user_id, name, DEFAULT_GROUP_NAME).await?;
+        txn.commit().await?;
+        Ok(true)
+    }
+
+    async fn reorder(&self, user_id: i64, names: &[String]) -> Result<(), DbErr> {
+        let txn = self.db_conn.get_conn().begin().await?;
+        for (sort, name) in names.iter().enumerate() {
+            entity::Entity::update_many()
+                .col_expr(entity::Column::Sort, Expr::value(sort as i32))
+                .filter(entity::Column::UserId.eq(user_id))
+                .filter(entity::Column::Name.eq(name.as_str()))
+                .exec(&txn)
+                .await?;
+        }
+        txn.commit().await
+    }
+}
@@ -1 +1,5 @@
+pub mod audit;
+pub mod friend_apply;
+pub mod friend_team;
+pub mod relationship;
 pub mod user;
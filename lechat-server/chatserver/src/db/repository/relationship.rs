@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::prelude::{DateTimeUtc, Expr};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter,
+};
+use shaku::{Component, Interface};
+
+use crate::components::mysql::IMysqlService;
+use crate::db::entity::user_relation_ship as entity;
+use crate::db::entity::user_relation_ship::Model;
+
+/// 默认好友分组
+pub const DEFAULT_GROUP_NAME: &str = "我的好友";
+
+/// 当前用户视角下的好友关系, 分组及备注均为当前用户设置的
+#[derive(Debug, Clone)]
+pub struct Friendship {
+    pub id: i64,
+    pub user_id: i64,
+    pub friend_id: i64,
+    pub group_name: String,
+    pub mark_name: Option<String>,
+    pub update_time: DateTimeUtc,
+    pub deleted: bool,
+}
+
+impl Friendship {
+    fn from_model(row: Model, user_id: i64) -> Self {
+        let (friend_id, group_name, mark_name) = if row.user_id1 == user_id {
+            (row.user_id2, row.user1_group_name, row.user1_mark_name)
+        } else {
+            (row.user_id1, row.user2_group_name, row.user2_mark_name)
+        };
+        Friendship {
+            id: row.id,
+            user_id,
+            friend_id,
+            group_name,
+            mark_name,
+            update_time: row.update_time,
+            deleted: row.deleted,
+        }
+    }
+}
+
+#[async_trait]
+pub trait IRelationshipRepository: Interface {
+    /// 查询用户的全部好友关系
+    async fn find_by_user(&self, id: i64) -> Result<Vec<Friendship>, DbErr>;
+    /// 查询用户在指定时间及之后有变化的好友关系, 包含已解除的
+    async fn find_changed(&self, id: i64, since: DateTimeUtc) -> Result<Vec<Friendship>, DbErr>;
+    /// 两个用户是否已经是好友
+    async fn exists(&self, id1: i64, id2: i64) -> Result<bool, DbErr>;
+    /// 解除好友关系, 记录保留并标记为已删除. 原本不是好友时返回false
+    async fn remove(&self, id1: i64, id2: i64) -> Result<bool, DbErr>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IRelationshipRepository)]
+pub struct RelationshipRepositoryImpl {
+    #[shaku(inject)]
+    db_conn: Arc<dyn IMysqlService>,
+}
+
+#[async_trait]
+impl IRelationshipRepository for RelationshipRepositoryImpl {
+    async fn find_by_user(&self, id: i64) -> Result<Vec<Friendship>, DbErr> {
+        let rows = entity::Entity::find()
+            .filter(user_condition(id))
+            .filter(entity::Column::Deleted.eq(false))
+            .all(self.db_conn.get_conn().as_ref())
+            .await?;
+        Ok(rows.into_iter().map(|r| Friendship::from_model(r, id)).collect())
+    }
+
+    async fn find_changed(&self, id: i64, since: DateTimeUtc) -> Result<Vec<Friendship>, DbErr> {
+        let rows = entity::Entity::find()
+            .filter(user_condition(id))
+            .filter(entity::Column::UpdateTime.gte(since))
+            .all(self.db_conn.get_conn().as_ref())
+            .await?;
+        Ok(rows.into_iter().map(|r| Friendship::from_model(r, id)).collect())
+    }
+
+    async fn exists(&self, id1: i64, id2: i64) -> Result<bool, DbErr> {
+        let count = entity::Entity::find()
+            .filter(pair_condition(id1, id2))
+            .filter(entity::Column::Deleted.eq(false))
+            .count(self.db_conn.get_conn().as_ref())
+            .await?;
+        Ok(count > 0)
+    }
+
+    async fn remove(&self, id1: i64, id2: i64) -> Result<bool, DbErr> {
+        let res = entity::Entity::update_many()
+            .col_expr(entity::Column::Deleted, Expr::value(true))
+            .col_expr(entity::Column::UpdateTime, Expr::value(chrono::Utc::now()))
+            .filter(pair_condition(id1, id2))
+            .filter(entity::Column::Deleted.eq(false))
+            .exec(self.db_conn.get_conn().as_ref())
+            .await?;
+        Ok(res.rows_affected > 0)
+    }
+}
+
+/// 建立好友关系, 曾经是好友的恢复原记录并重置分组及备注, 供其他仓储在事务中调用
+pub(crate) async fn befriend<C: ConnectionTrait>(conn: &C, id1: i64, id2: i64) -> Result<(), DbErr> {
+    let (user_id1, user_id2) = normalize(id1, id2);
+    let existing = entity::Entity::find().filter(pair_condition(id1, id2)).one(conn).await?;
+    match existing {
+        Some(row) if row.deleted => {
+            let mut model: entity::ActiveModel = row.into();
+            model.user1_group_name = Set(DEFAULT_GROUP_NAME.to_string());
+            model.user1_mark_name = Set(None);
+            model.user2_group_name = Set(DEFAULT_GROUP_NAME.to_string());
+            model.user2_mark_name = Set(None);
+            model.update_time = Set(chrono::Utc::now());
+            model.deleted = Set(false);
+            model.update(conn).await?;
+        }
+        Some(_) => {}
+        None => {
+            let model = entity::ActiveModel {
+                user_id1: Set(user_id1),
+                user_id2: Set(user_id2),
+                ..Default::default()
+            };
+            model.insert(conn).await?;
+        }
+    }
+    Ok(())
+}
+
+/// 将用户某个分组下的好友移到另一个分组, 供其他仓储在事务中调用
+pub(crate) async fn move_group<C: ConnectionTrait>(
+    conn: &C,
+    user_id: i64,
+    from: &str,
+    to: &str,
+) -> Result<u64, DbErr> {
+    let now = chrono::Utc::now();
+    let side1 = entity::Entity::update_many()
+        .col_expr(entity::Column::User1GroupName, Expr::value(to))
+        .col_expr(entity::Column::UpdateTime, Expr::value(now))
+        .filter(entity::Column::UserId1.eq(user_id))
+        .filter(entity::Column::User1GroupName.eq(from))
+        .filter(entity::Column::Deleted.eq(false))
+        .exec(conn)
+        .await?;
+    let side2 = entity::Entity::update_many()
+        .col_expr(entity::Column::User2GroupName, Expr::value(to))
+        .col_expr(entity::Column::UpdateTime, Expr::value(now))
+        .filter(entity::Column::UserId2.eq(user_id))
+        .filter(entity::Column::User2GroupName.eq(from))
+        .filter(entity::Column::Deleted.eq(false))
+        .exec(conn)
+        .await?;
+    Ok(side1.rows_affected + side2.rows_affected)
+}
+
+/// 两个用户的关系只存一条记录, `user_id1`为较小的ID
+fn normalize(id1: i64, id2: i64) -> (i64, i64) {
+    if id1 < id2 {
+        (id1, id2)
+    } else {
+        (id2, id1)
+    }
+}
+
+fn pair_condition(id1: i64, id2: i64) -> Condition {
+    let (user_id1, user_id2) = normalize(id1, id2);
+    Condition::all()
+        .add(entity::Column::UserId1.eq(user_id1))
+        .add(entity::Column::UserId2.eq(user_id2))
+}
+
+fn user_condition(id: i64) -> Condition {
+    Condition::any()
+        .add(entity::Column::UserId1.eq(id))
+        .add(entity::Column::UserId2.eq(id))
+}
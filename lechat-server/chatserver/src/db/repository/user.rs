@@ -1,27 +1,71 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, ColumnTrait, DbErr, EntityTrait, QueryFilter};
+use sea_orm::prelude::{DateTime, Expr};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DbErr, DerivePartialModel, EntityTrait, FromQueryResult, PaginatorTrait,
+    QueryFilter, QueryOrder,
+};
 use shaku::{Component, Interface};
 
 use crate::components::mysql::IMysqlService;
+use crate::db::entity::sea_orm_active_enums::UserStatus;
 use crate::db::entity::user as entity;
 use crate::db::entity::user::{ActiveModel, Model};
 
+/// 搜索条件
+#[derive(Debug, Clone)]
+pub enum UserSearch {
+    UserId(String),
+    /// 精确匹配用户名
+    Username(String),
+    /// 精确匹配手机号, 只返回允许通过手机号搜索的用户
+    Phone(String),
+    /// 昵称前缀
+    NicknamePrefix(String),
+}
+
+/// 用户公开资料, 只查询可以公开的列
+#[derive(Debug, DerivePartialModel, FromQueryResult)]
+#[sea_orm(entity = "entity::Entity")]
+pub struct PublicUser {
+    pub user_id: String,
+    pub user_name: String,
+    pub nick_name: String,
+    pub gender: i32,
+    pub signature: Option<String>,
+    pub facetype: Option<i32>,
+    pub customface: Option<String>,
+    pub customfacefmt: Option<String>,
+}
+
 #[async_trait]
 pub trait IUserRepository: Interface {
     async fn find_by_id(&self, id: i64) -> Result<Option<Model>, DbErr>;
+    async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Model>, DbErr>;
     async fn find_by_user_id(&self, uid: &str) -> Result<Option<Model>, DbErr>;
     async fn find_by_name(&self, name: &str) -> Result<Option<Model>, DbErr>;
     async fn find_by_phone(&self, phone: &str) -> Result<Option<Model>, DbErr>;
     async fn find_by_email(&self, email: &str) -> Result<Option<Model>, DbErr>;
+    /// 分页搜索已验证的用户, page从0开始, 返回当前页及总数
+    async fn search(
+        &self,
+        cond: UserSearch,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<PublicUser>, u64), DbErr>;
     async fn add(&self, user: entity::ActiveModel) -> Result<Model, DbErr>;
     async fn update(&self, user: ActiveModel) -> Result<Model, DbErr>;
+    /// 待验证的账号改为正常, 返回是否有账号被激活
+    async fn activate(&self, uid: &str) -> Result<bool, DbErr>;
+    /// 删除在指定时间前注册且仍未验证的账号, 返回删除的数量
+    async fn delete_unverified(&self, registered_before: DateTime) -> Result<u64, DbErr>;
 }
 
 #[derive(Component)]
 #[shaku(interface = IUserRepository)]
 pub struct UserRepositoryImpl {
+    #[shaku(inject)]
     db_conn: Arc<dyn IMysqlService>,
 }
 
@@ -31,6 +75,16 @@ impl IUserRepository for UserRepositoryImpl {
         entity::Entity::find_by_id(id).one(self.db_conn.get_conn().as_ref()).await
     }
 
+    async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Model>, DbErr> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        entity::Entity::find()
+            .filter(entity::Column::Id.is_in(ids.iter().copied()))
+            .all(self.db_conn.get_conn().as_ref())
+            .await
+    }
+
     async fn find_by_user_id(&self, uid: &str) -> Result<Option<Model>, DbErr> {
         entity::Entity::find()
             .filter(entity::Column::UserId.eq(uid))
@@ -40,7 +94,7 @@ impl IUserRepository for UserRepositoryImpl {
 
     async fn find_by_name(&self, name: &str) -> Result<Option<Model>, DbErr> {
         entity::Entity::find()
-            .filter(entity::Column::UserName.contains(name))
+            .filter(entity::Column::UserName.eq(name))
             .one(self.db_conn.get_conn().as_ref())
             .await
     }
@@ -59,6 +113,32 @@ impl IUserRepository for UserRepositoryImpl {
             .await
     }
 
+    async fn search(
+        &self,
+        cond: UserSearch,
+        page: u64,
+        page_size: u64,
+    ) -> Result<(Vec<PublicUser>, u64), DbErr> {
+        let condition = match cond {
+            UserSearch::UserId(uid) => entity::Column::UserId.eq(uid),
+            UserSearch::Username(name) => entity::Column::UserName.eq(name),
+            UserSearch::Phone(phone) => {
+                entity::Column::Phone.eq(phone).and(entity::Column::PhoneSearchable.eq(true))
+            }
+            UserSearch::NicknamePrefix(prefix) => entity::Column::NickName.starts_with(escape_like(&prefix)),
+        };
+        let conn = self.db_conn.get_conn();
+        let paginator = entity::Entity::find()
+            .filter(condition)
+            .filter(entity::Column::Status.eq(UserStatus::Active))
+            .order_by_asc(entity::Column::Id)
+            .into_partial_model::<PublicUser>()
+            .paginate(conn.as_ref(), page_size);
+        let total = paginator.num_items().await?;
+        let users = paginator.fetch_page(page).await?;
+        Ok((users, total))
+    }
+
     async fn add(&self, user: ActiveModel) -> Result<Model, DbErr> {
         user.insert(self.db_conn.get_conn().as_ref()).await
     }
@@ -66,4 +146,35 @@ impl IUserRepository for UserRepositoryImpl {
     async fn update(&self, user: ActiveModel) -> Result<Model, DbErr> {
         user.update(self.db_conn.get_conn().as_ref()).await
     }
+
+    async fn activate(&self, uid: &str) -> Result<bool, DbErr> {
+        let res = entity::Entity::update_many()
+            .col_expr(entity::Column::Status, Expr::value(UserStatus::Active))
+            .filter(entity::Column::UserId.eq(uid))
+            .filter(entity::Column::Status.eq(UserStatus::PendingVerification))
+            .exec(self.db_conn.get_conn().as_ref())
+            .await?;
+        Ok(res.rows_affected > 0)
+    }
+
+    async fn delete_unverified(&self, registered_before: DateTime) -> Result<u64, DbErr> {
+        let res = entity::Entity::delete_many()
+            .filter(entity::Column::Status.eq(UserStatus::PendingVerification))
+            .filter(entity::Column::RegisterTime.lt(registered_before))
+            .exec(self.db_conn.get_conn().as_ref())
+            .await?;
+        Ok(res.rows_affected)
+    }
+}
+
+/// 转义LIKE中的通配符, MySQL默认以`\`转义
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
 }
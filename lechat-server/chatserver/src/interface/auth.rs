@@ -0,0 +1,45 @@
+use std::future::{ready, Ready};
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{Payload, ServiceRequest, ServiceResponse};
+use actix_web::http::header;
+use actix_web::middleware::Next;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use shaku::HasComponent;
+
+use crate::base::response::Error;
+use crate::service;
+use crate::service::token::{AuthUser, ITokenService};
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+/// 认证中间件, 校验`Authorization: Bearer <access_token>`并将当前用户注入请求
+pub async fn authenticate(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix(BEARER_PREFIX))
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .ok_or(Error::NotLogin)?;
+
+    let modules = service::service_factory()?;
+    let token_service: &dyn ITokenService = modules.resolve_ref();
+    let user = token_service.authenticate(token).await?;
+    req.extensions_mut().insert(user);
+    next.call(req).await
+}
+
+/// 受保护的接口通过该提取器获取当前用户
+impl FromRequest for AuthUser {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(req.extensions().get::<AuthUser>().cloned().ok_or(Error::NotLogin))
+    }
+}
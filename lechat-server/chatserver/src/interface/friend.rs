@@ -0,0 +1,220 @@
+use actix_web::middleware::from_fn;
+use actix_web::web::ServiceConfig;
+use actix_web::{delete, get, patch, post, put, web};
+use serde::{Deserialize, Serialize};
+use shaku::HasComponent;
+use validator::Validate;
+
+use crate::base::response::{Error, Reply, Response};
+use crate::interface::auth;
+use crate::network::stubs::chatmsg::FriendOperationApplyType;
+use crate::service;
+use crate::service::friend::{
+    FriendApplyInfo, FriendApplyRequest, FriendList, FriendListRequest, IFriendService,
+};
+use crate::service::friend_team::{
+    AddTeamRequest, IFriendTeamService, RenameTeamRequest, ReorderTeamsRequest, TeamList,
+};
+use crate::service::token::AuthUser;
+use crate::service::user::PublicProfile;
+
+#[derive(Debug, Deserialize)]
+pub struct ReplyApplyRequest {
+    /// true 接受, false 拒绝
+    pub accept: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FriendApplyReply {
+    pub apply: FriendApplyInfo,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FriendAppliesReply {
+    pub applies: Vec<FriendApplyInfo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeleteFriendReply {
+    pub user: PublicProfile,
+}
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        web::scope("/friend")
+            .wrap(from_fn(auth::authenticate))
+            .service(friends)
+            .service(apply_friend)
+            .service(applies)
+            .service(reply_apply)
+            .service(teams)
+            .service(add_team)
+            .service(reorder_teams)
+            .service(rename_team)
+            .service(delete_team)
+            .service(delete_friend),
+    );
+}
+
+/// 按分组返回好友列表, 传入上次的version时增量同步
+#[get("/list")]
+async fn friends(user: AuthUser, query: web::Query<FriendListRequest>) -> Reply<FriendList> {
+    let modules = service::service_factory()?;
+    let friend_service: &dyn IFriendService = modules.resolve_ref();
+    let list = friend_service.list_friends(&user.user_id, query.since).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(list))
+}
+
+#[post("/apply")]
+async fn apply_friend(user: AuthUser, body: web::Json<FriendApplyRequest>) -> Reply<FriendApplyReply> {
+    let body = body.into_inner();
+    if let Err(err) = body.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+
+    let modules = service::service_factory()?;
+    let friend_service: &dyn IFriendService = modules.resolve_ref();
+    let apply = friend_service.apply(&user.user_id, body).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(FriendApplyReply { apply }))
+}
+
+/// 收到及发出的好友申请
+#[get("/applies")]
+async fn applies(user: AuthUser) -> Reply<FriendAppliesReply> {
+    let modules = service::service_factory()?;
+    let friend_service: &dyn IFriendService = modules.resolve_ref();
+    let applies = friend_service.list_applies(&user.user_id).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(FriendAppliesReply { applies }))
+}
+
+#[post("/apply/{apply_id}/reply")]
+async fn reply_apply(
+    user: AuthUser,
+    path: web::Path<i64>,
+    body: web::Json<ReplyApplyRequest>,
+) -> Reply<FriendApplyReply> {
+    let answer = if body.accept {
+        FriendOperationApplyType::APPLY_ACCEPTED
+    } else {
+        FriendOperationApplyType::APPLY_REFUSED
+    };
+    let modules = service::service_factory()?;
+    let friend_service: &dyn IFriendService = modules.resolve_ref();
+    let apply = friend_service
+        .reply_apply(&user.user_id, path.into_inner(), answer)
+        .await
+        .map_err(|err| {
+            tracing::error!("{err:#}");
+            err
+        })?;
+
+    Ok(Response::ok(FriendApplyReply { apply }))
+}
+
+/// 全部分组, 默认分组在最前
+#[get("/teams")]
+async fn teams(user: AuthUser) -> Reply<TeamList> {
+    let modules = service::service_factory()?;
+    let team_service: &dyn IFriendTeamService = modules.resolve_ref();
+    let list = team_service.list_teams(&user.user_id).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(list))
+}
+
+#[post("/teams")]
+async fn add_team(user: AuthUser, body: web::Json<AddTeamRequest>) -> Reply<TeamList> {
+    let modules = service::service_factory()?;
+    let team_service: &dyn IFriendTeamService = modules.resolve_ref();
+    let list = team_service.add_team(&user.user_id, &body.name).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(list))
+}
+
+#[put("/teams/order")]
+async fn reorder_teams(user: AuthUser, body: web::Json<ReorderTeamsRequest>) -> Reply<TeamList> {
+    let modules = service::service_factory()?;
+    let team_service: &dyn IFriendTeamService = modules.resolve_ref();
+    let list = team_service
+        .reorder_teams(&user.user_id, body.into_inner().names)
+        .await
+        .map_err(|err| {
+            tracing::error!("{err:#}");
+            err
+        })?;
+
+    Ok(Response::ok(list))
+}
+
+#[patch("/teams/{name}")]
+async fn rename_team(
+    user: AuthUser,
+    path: web::Path<String>,
+    body: web::Json<RenameTeamRequest>,
+) -> Reply<TeamList> {
+    let modules = service::service_factory()?;
+    let team_service: &dyn IFriendTeamService = modules.resolve_ref();
+    let list = team_service
+        .rename_team(&user.user_id, &path.into_inner(), &body.new_name)
+        .await
+        .map_err(|err| {
+            tracing::error!("{err:#}");
+            err
+        })?;
+
+    Ok(Response::ok(list))
+}
+
+/// 删除分组, 分组下的好友移到默认分组
+#[delete("/teams/{name}")]
+async fn delete_team(user: AuthUser, path: web::Path<String>) -> Reply<TeamList> {
+    let modules = service::service_factory()?;
+    let team_service: &dyn IFriendTeamService = modules.resolve_ref();
+    let list = team_service
+        .delete_team(&user.user_id, &path.into_inner())
+        .await
+        .map_err(|err| {
+            tracing::error!("{err:#}");
+            err
+        })?;
+
+    Ok(Response::ok(list))
+}
+
+/// 解除好友关系
+#[delete("/{user_id}")]
+async fn delete_friend(user: AuthUser, path: web::Path<String>) -> Reply<DeleteFriendReply> {
+    let modules = service::service_factory()?;
+    let friend_service: &dyn IFriendService = modules.resolve_ref();
+    let friend = friend_service
+        .delete_friend(&user.user_id, &path.into_inner())
+        .await
+        .map_err(|err| {
+            tracing::error!("{err:#}");
+            err
+        })?;
+
+    Ok(Response::ok(DeleteFriendReply { user: friend }))
+}
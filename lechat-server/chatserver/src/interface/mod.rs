@@ -2,6 +2,9 @@ use anyhow::Context;
 
 use crate::base::response::Result;
 
+pub mod auth;
+pub mod friend;
+pub mod monitor;
 pub mod user;
 
 pub trait Validate {
@@ -0,0 +1,76 @@
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header;
+use actix_web::middleware::{from_fn, Next};
+use actix_web::web::ServiceConfig;
+use actix_web::{delete, get, web};
+use serde::Serialize;
+use shaku::HasComponent;
+use subtle::ConstantTimeEq;
+
+use crate::base::config::Config;
+use crate::base::response::{Error, Reply, Response};
+use crate::service;
+use crate::service::login_guard::{ILoginGuardService, LockKind, Lockout};
+
+const BEARER_PREFIX: &str = "Bearer ";
+
+#[derive(Debug, Serialize)]
+pub struct LockoutsReply {
+    pub lockouts: Vec<Lockout>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnlockReply {
+    /// 解除前是否处于锁定状态
+    pub unlocked: bool,
+}
+
+/// 监控接口, 仅在监控端口上提供
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(
+        web::scope("/monitor")
+            .wrap(from_fn(authenticate))
+            .service(lockouts)
+            .service(unlock),
+    );
+}
+
+/// 校验`Authorization: Bearer <monitor.token>`, 未配置token时拒绝全部请求
+async fn authenticate(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let expected = req
+        .app_data::<web::Data<Config>>()
+        .and_then(|cfg| cfg.monitor.token.clone())
+        .ok_or(Error::NotLogin)?;
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix(BEARER_PREFIX))
+        .map(str::trim)
+        .ok_or(Error::NotLogin)?;
+    if !bool::from(token.as_bytes().ct_eq(expected.as_bytes())) {
+        return Err(Error::TokenInvalid.into());
+    }
+    next.call(req).await
+}
+
+#[get("/lockouts")]
+async fn lockouts() -> Reply<LockoutsReply> {
+    let modules = service::service_factory()?;
+    let login_guard: &dyn ILoginGuardService = modules.resolve_ref();
+    let lockouts = login_guard.lockouts().await?;
+    Ok(Response::ok(LockoutsReply { lockouts }))
+}
+
+#[delete("/lockouts/{kind}/{value}")]
+async fn unlock(path: web::Path<(LockKind, String)>) -> Reply<UnlockReply> {
+    let (kind, value) = path.into_inner();
+    let modules = service::service_factory()?;
+    let login_guard: &dyn ILoginGuardService = modules.resolve_ref();
+    let unlocked = login_guard.unlock(kind, &value).await?;
+    Ok(Response::ok(UnlockReply { unlocked }))
+}
@@ -1,26 +1,91 @@
+use actix_web::middleware::from_fn;
 use actix_web::web::ServiceConfig;
-use actix_web::{get, post, web};
-use serde::Serialize;
+use actix_web::{get, patch, post, web, HttpRequest};
+use serde::{Deserialize, Serialize};
 use shaku::HasComponent;
 use validator::Validate;
 
 use crate::base::response::{Error, Reply, Response};
+use crate::interface::auth;
 use crate::service;
-use crate::service::user::{IUserService, SignInRequest, SignUpRequest, UserInfo};
+use crate::service::password_reset::{
+    ConfirmPasswordResetRequest, IPasswordResetService, PasswordResetRequest,
+};
+use crate::service::sms::{ISmsCodeService, SendCodeRequest};
+use crate::service::token::{AuthUser, ITokenService, TokenPair};
+use crate::service::user::{
+    IUserService, MobileSignInRequest, ModifyPasswordRequest, SearchUserRequest, SearchUserResult,
+    SignInRequest, SignUpRequest, UpdateUserInfoRequest, UserInfo,
+};
+use crate::service::verification::{IVerificationService, VerifyEmailRequest, VerifyMobileRequest};
 
 #[derive(Debug, Serialize)]
 pub struct SignUpReply {
     pub user: UserInfo,
 }
 
+#[derive(Debug, Serialize)]
+pub struct SignInReply {
+    pub user: UserInfo,
+    pub token: TokenPair,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RefreshTokenRequest {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefreshTokenReply {
+    pub token: TokenPair,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SendCodeReply {
+    /// 验证码有效期(秒)
+    pub expires_in: u64,
+}
+
 #[derive(Debug, Default, Serialize)]
-pub struct SignInReply;
+pub struct ModifyPasswordReply;
+
+#[derive(Debug, Serialize)]
+pub struct UpdateUserInfoReply {
+    pub user: UserInfo,
+}
 
 #[derive(Debug, Default, Serialize)]
 pub struct SignOutReply;
 
+#[derive(Debug, Default, Serialize)]
+pub struct PasswordResetReply;
+
+#[derive(Debug, Default, Serialize)]
+pub struct VerifyReply;
+
 pub fn config(cfg: &mut ServiceConfig) {
-    cfg.service(web::scope("/user").service(sign_up).service(sign_in).service(sign_out));
+    cfg.service(
+        web::scope("/user")
+            .service(sign_up)
+            .service(sign_in)
+            .service(send_code)
+            .service(sign_in_by_mobile)
+            .service(refresh_token)
+            .service(request_password_reset)
+            .service(confirm_password_reset)
+            .service(verify_email)
+            .service(verify_mobile)
+            // 以下接口需要登录
+            .service(
+                web::scope("")
+                    .wrap(from_fn(auth::authenticate))
+                    .service(modify_password)
+                    .service(update_user_info)
+                    .service(search_users)
+                    .service(sign_out)
+                    .service(sign_out_all),
+            ),
+    );
 }
 
 #[post("/signup")]
@@ -44,14 +109,14 @@ async fn sign_up(body: web::Json<SignUpRequest>) -> Reply<SignUpReply> {
     let user_service: &dyn IUserService = modules.resolve_ref();
     let user_info = user_service.sign_up(req).await.map_err(|err| {
         tracing::error!("{err:#}");
-        Error::InternalServerError
+        err
     })?;
     let reply = SignUpReply { user: user_info };
     Ok(Response::ok(reply))
 }
 
 #[post("/signin")]
-async fn sign_in(body: web::Json<SignInRequest>) -> Reply<SignInReply> {
+async fn sign_in(http_req: HttpRequest, body: web::Json<SignInRequest>) -> Reply<SignInReply> {
     // 校验参数
     let req = body.into_inner();
     if let Err(err) = req.validate() {
@@ -69,27 +134,274 @@ async fn sign_in(body: web::Json<SignInRequest>) -> Reply<SignInReply> {
 
     let modules = service::service_factory()?;
     let user_service: &dyn IUserService = modules.resolve_ref();
-    user_service.sign_in(req).await.map_err(|err| {
+    let ip = http_req.peer_addr().map(|addr| addr.ip().to_string()).unwrap_or_default();
+    let info = user_service.sign_in(req, &ip).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(SignInReply {
+        user: info.user,
+        token: info.token,
+    }))
+}
+
+#[post("/sms/code")]
+async fn send_code(req: HttpRequest, body: web::Json<SendCodeRequest>) -> Reply<SendCodeReply> {
+    let body = body.into_inner();
+    if let Err(err) = body.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+    // 按连接的对端地址限流, 不信任可伪造的转发头
+    let ip = req.peer_addr().map(|addr| addr.ip().to_string()).unwrap_or_default();
+
+    let modules = service::service_factory()?;
+    let sms_service: &dyn ISmsCodeService = modules.resolve_ref();
+    let ttl = sms_service.send_code(&body.mobile, &ip).await?;
+    Ok(Response::ok(SendCodeReply {
+        expires_in: ttl.as_secs(),
+    }))
+}
+
+#[post("/signin/mobile")]
+async fn sign_in_by_mobile(body: web::Json<MobileSignInRequest>) -> Reply<SignInReply> {
+    let req = body.into_inner();
+    if let Err(err) = req.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+
+    let modules = service::service_factory()?;
+    let user_service: &dyn IUserService = modules.resolve_ref();
+    let info = user_service.sign_in_by_mobile(req).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(SignInReply {
+        user: info.user,
+        token: info.token,
+    }))
+}
+
+#[post("/token/refresh")]
+async fn refresh_token(body: web::Json<RefreshTokenRequest>) -> Reply<RefreshTokenReply> {
+    let req = body.into_inner();
+    if req.refresh_token.is_empty() {
+        return Err(Error::ParamInvalid("刷新令牌不能为空".to_string()));
+    }
+    let modules = service::service_factory()?;
+    let token_service: &dyn ITokenService = modules.resolve_ref();
+    let token = token_service.refresh(&req.refresh_token).await?;
+    Ok(Response::ok(RefreshTokenReply { token }))
+}
+
+/// 修改密码, 当前设备保持登录
+#[post("/password")]
+async fn modify_password(
+    req: HttpRequest,
+    user: AuthUser,
+    body: web::Json<ModifyPasswordRequest>,
+) -> Reply<ModifyPasswordReply> {
+    let body = body.into_inner();
+    if let Err(err) = body.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+    let ip = req.peer_addr().map(|addr| addr.ip().to_string());
+
+    let modules = service::service_factory()?;
+    let user_service: &dyn IUserService = modules.resolve_ref();
+    user_service.modify_password(&user, body, ip).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(ModifyPasswordReply))
+}
+
+/// 发送重置密码邮件, 邮箱未注册时同样返回成功
+#[post("/password/reset")]
+async fn request_password_reset(body: web::Json<PasswordResetRequest>) -> Reply<PasswordResetReply> {
+    let body = body.into_inner();
+    if let Err(err) = body.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+
+    let modules = service::service_factory()?;
+    let reset_service: &dyn IPasswordResetService = modules.resolve_ref();
+    reset_service.request_reset(&body.email).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(PasswordResetReply))
+}
+
+/// 通过邮件中的令牌设置新密码
+#[post("/password/reset/confirm")]
+async fn confirm_password_reset(
+    req: HttpRequest,
+    body: web::Json<ConfirmPasswordResetRequest>,
+) -> Reply<PasswordResetReply> {
+    let body = body.into_inner();
+    if let Err(err) = body.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+
+    let modules = service::service_factory()?;
+    let reset_service: &dyn IPasswordResetService = modules.resolve_ref();
+    let ip = req.peer_addr().map(|addr| addr.ip().to_string());
+    reset_service.confirm_reset(body, ip).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(PasswordResetReply))
+}
+
+#[post("/verify/email")]
+async fn verify_email(body: web::Json<VerifyEmailRequest>) -> Reply<VerifyReply> {
+    let body = body.into_inner();
+    if let Err(err) = body.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+
+    let modules = service::service_factory()?;
+    let verification: &dyn IVerificationService = modules.resolve_ref();
+    verification.verify_email(&body.token).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(VerifyReply))
+}
+
+#[post("/verify/mobile")]
+async fn verify_mobile(body: web::Json<VerifyMobileRequest>) -> Reply<VerifyReply> {
+    let body = body.into_inner();
+    if let Err(err) = body.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+
+    let modules = service::service_factory()?;
+    let verification: &dyn IVerificationService = modules.resolve_ref();
+    verification.verify_mobile(&body.mobile, &body.code).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(VerifyReply))
+}
+
+/// 修改资料, 只修改设置了的字段
+#[patch("/info")]
+async fn update_user_info(
+    user: AuthUser,
+    body: web::Json<UpdateUserInfoRequest>,
+) -> Reply<UpdateUserInfoReply> {
+    let body = body.into_inner();
+    if let Err(err) = body.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
+    }
+
+    let modules = service::service_factory()?;
+    let user_service: &dyn IUserService = modules.resolve_ref();
+    let user = user_service.update_user_info(&user.user_id, body).await.map_err(|err| {
         tracing::error!("{err:#}");
-        Error::InternalServerError
+        err
     })?;
 
-    Ok(Response::ok(SignInReply::default()))
+    Ok(Response::ok(UpdateUserInfoReply { user }))
 }
 
-#[get("/signout/{user_id}")]
-async fn sign_out(user_id: web::Path<String>) -> Reply<SignOutReply> {
-    if user_id.is_empty() {
-        return Err(Error::ParamInvalid("用户名不能为空".to_string()));
+#[get("/search")]
+async fn search_users(_user: AuthUser, query: web::Query<SearchUserRequest>) -> Reply<SearchUserResult> {
+    let query = query.into_inner();
+    if let Err(err) = query.validate() {
+        for (_, v) in err.field_errors() {
+            if let Some(msg) = v.first().and_then(|v| v.message.as_ref()) {
+                return Err(Error::ParamInvalid(msg.to_string()));
+            }
+        }
+        return Err(Error::ParamInvalid("参数不合法".to_string()));
     }
+
+    let modules = service::service_factory()?;
+    let user_service: &dyn IUserService = modules.resolve_ref();
+    let res = user_service.search_users(query).await.map_err(|err| {
+        tracing::error!("{err:#}");
+        err
+    })?;
+
+    Ok(Response::ok(res))
+}
+
+/// 退出当前设备
+#[post("/signout")]
+async fn sign_out(user: AuthUser) -> Reply<SignOutReply> {
+    let modules = service::service_factory()?;
+    let user_service: &dyn IUserService = modules.resolve_ref();
+    user_service
+        .sign_out(&user.user_id, Some(user.client_type))
+        .await
+        .map_err(|err| {
+            tracing::error!("{err:#}");
+            err
+        })?;
+
+    Ok(Response::ok(SignOutReply))
+}
+
+/// 退出全部设备
+#[post("/signout/all")]
+async fn sign_out_all(user: AuthUser) -> Reply<SignOutReply> {
     let modules = service::service_factory()?;
     let user_service: &dyn IUserService = modules.resolve_ref();
-    user_service.sign_out(&user_id.into_inner()).await.map_err(|err| {
+    user_service.sign_out(&user.user_id, None).await.map_err(|err| {
         tracing::error!("{err:#}");
-        Error::InternalServerError
+        err
     })?;
 
-    Ok(Response::ok(SignOutReply::default()))
+    Ok(Response::ok(SignOutReply))
 }
 
 // async fn find_friend(cond: web::Json<FindFriendRequest>) -> Reply<> {
@@ -6,8 +6,10 @@ use actix_web::{App, get, HttpResponse, HttpServer, web};
 use clap::Parser;
 use chatserver::base::app_state;
 use chatserver::error::Result;
-use chatserver::{base, interface, Error};
+use chatserver::{base, components, interface, network, service, Error};
 use chatserver::db::Data;
+use sea_orm::TransactionTrait;
+use shaku::HasComponent;
 
 #[derive(Parser, Debug, Clone)]
 #[command(author, version, about)]
@@ -29,13 +31,35 @@ async fn main() -> Result<()> {
     let cfg_ = cfg.clone();
     let log_cleaner = base::log::init_logger(&cfg)?;
     let addr = format!("{}:{}", cfg.http.ip, cfg.http.port);
-    tracing::info!("mirco-chat lechat-server starts running on {}", addr);
+    tracing::info!("mirco-chat lechat-server starts running on {}, tls: {}", addr, cfg.http.tls.is_some());
 
     // database connection
     // let conn = base::singleton::db::DbPool::get_instance(cfg_.clone()).await?;
     let db = Arc::new(Data::new(cfg.clone()).await?);
     let app_state = web::Data::new(app_state::AppState::new(cfg_, db.clone()));
-    let _ = HttpServer::new(move || {
+
+    // service components
+    let db_conn = Arc::new(components::mysql::init_db_conn(&cfg.database).await.map_err(Error::DatabaseError)?);
+    let db_tx = Arc::new(db_conn.begin().await.map_err(Error::DatabaseError)?);
+    let redis_cli = Arc::new(
+        components::redis::init_redis(&cfg.redis).await.map_err(|e| Error::ServerError(e.to_string()))?,
+    );
+    let modules = components::register_components(cfg.clone(), db_conn, db_tx, redis_cli)
+        .await
+        .map_err(|e| Error::ServerError(e.to_string()))?;
+
+    // client long connection gateway
+    tokio::spawn(network::session::reap_idle_sessions(modules.resolve(), cfg.clone()));
+    tokio::spawn(network::delivery::retransmit_pushes(modules.resolve(), cfg.clone()));
+    tokio::spawn(network::registry::keep_instance_alive(modules.resolve(), modules.resolve()));
+    tokio::spawn(network::registry::listen_kick(modules.resolve(), modules.resolve()));
+    tokio::spawn(network::delivery::listen_push(modules.resolve(), modules.resolve(), modules.resolve()));
+    tokio::spawn(service::verification::purge_unverified_users(modules.resolve(), cfg.clone()));
+    let gateway = network::gateway::Gateway::bind(&cfg, modules).await?;
+    tokio::spawn(gateway.run());
+    let codec = web::Data::new(network::codec::PacketCodec::from(&cfg.codec));
+
+    let server = HttpServer::new(move || {
         let cors = Cors::default()
             .allow_any_header()
             .allow_any_method()
@@ -44,17 +68,39 @@ async fn main() -> Result<()> {
         App::new()
             .wrap(cors)
             // .app_data(app_state.clone())
+            .app_data(codec.clone())
             .service(index)
             .configure(|cfg| {
                 interface::user::config(cfg);
+                interface::friend::config(cfg);
+                network::ws::config(cfg);
             })
     })
         .workers(4)
-        .shutdown_timeout(5)
-        .bind(addr)
-        .map_err(|e| Error::ServerError(e.to_string()))?
-        .run()
-        .await;
+        .shutdown_timeout(5);
+    let server = match &cfg.http.tls {
+        Some(tls) => server.bind_rustls_0_23(addr, base::tls::server_config(tls)?),
+        None => server.bind(addr),
+    };
+    let server = server.map_err(|e| Error::ServerError(e.to_string()))?.run();
+
+    // monitor api
+    let monitor_addr = format!("{}:{}", cfg.monitor.ip, cfg.monitor.port);
+    tracing::info!("monitor api listens on {}, tls: {}", monitor_addr, cfg.monitor.tls.is_some());
+    let monitor_cfg = web::Data::from(cfg.clone());
+    let monitor = HttpServer::new(move || {
+        App::new()
+            .app_data(monitor_cfg.clone())
+            .configure(interface::monitor::config)
+    })
+        .workers(1)
+        .shutdown_timeout(5);
+    let monitor = match &cfg.monitor.tls {
+        Some(tls) => monitor.bind_rustls_0_23(monitor_addr, base::tls::server_config(tls)?),
+        None => monitor.bind(monitor_addr),
+    };
+    let monitor = monitor.map_err(|e| Error::ServerError(e.to_string()))?.run();
+    let _ = futures::future::join(server, monitor).await;
 
     log_cleaner();
     Ok(())
@@ -0,0 +1,349 @@
+use std::io::{Read, Write};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use protobuf::{Enum, Message};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::base::config::CodecConfig;
+use crate::network::error::{Error, Result};
+use crate::network::packet::Packet;
+use crate::network::stubs::chatmsg::{Header, MsgType};
+
+/// 帧长度字段大小
+const FRAME_LEN_SIZE: usize = 4;
+/// 协议头长度字段大小
+const HEADER_LEN_SIZE: usize = 2;
+/// 消息类型字段大小
+const MSG_TYPE_SIZE: usize = 4;
+/// 默认最大帧长度 4M
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 4 * 1024 * 1024;
+/// 默认启用压缩的包体大小
+pub const DEFAULT_COMPRESS_THRESHOLD: usize = 1024;
+
+/// 长连接数据包编解码器, 网关与测试客户端共用
+///
+/// 帧格式: | frame_len(u32) | header_len(u16) | Header | body |
+///
+/// body压缩前格式: | msg_type(i32) | protobuf消息 |,
+/// 包体大小超过阈值时使用zlib压缩, 并在`Header`中记录压缩前后的大小
+#[derive(Debug, Clone)]
+pub struct PacketCodec {
+    max_frame_size: usize,
+    compress_threshold: usize,
+}
+
+impl PacketCodec {
+    pub fn new(max_frame_size: usize, compress_threshold: usize) -> Self {
+        PacketCodec {
+            max_frame_size,
+            compress_threshold,
+        }
+    }
+
+    pub fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+
+    /// 校验协议头中的大小并解压包体
+    fn unpack_body(&self, header: &Header, body: Bytes) -> Result<Bytes> {
+        let origin_size = usize::try_from(header.origin_size).map_err(|_| Error::SizeMismatch {
+            declared: header.origin_size as i64,
+            actual: body.len(),
+        })?;
+        if origin_size > self.max_frame_size {
+            return Err(Error::FrameTooLarge(origin_size));
+        }
+
+        if !header.compress {
+            if body.len() != origin_size {
+                return Err(Error::SizeMismatch {
+                    declared: header.origin_size as i64,
+                    actual: body.len(),
+                });
+            }
+            return Ok(body);
+        }
+
+        if usize::try_from(header.compress_size).ok() != Some(body.len()) {
+            return Err(Error::SizeMismatch {
+                declared: header.compress_size as i64,
+                actual: body.len(),
+            });
+        }
+        // 最多读取origin_size + 1个字节, 防止解压炸弹
+        let mut data = Vec::with_capacity(origin_size);
+        ZlibDecoder::new(body.as_ref())
+            .take(origin_size as u64 + 1)
+            .read_to_end(&mut data)?;
+        if data.len() != origin_size {
+            return Err(Error::SizeMismatch {
+                declared: header.origin_size as i64,
+                actual: data.len(),
+            });
+        }
+        Ok(Bytes::from(data))
+    }
+
+    /// 包体超过阈值时压缩, 压缩后没有变小则保持原样
+    fn pack_body(&self, body: Bytes, header: &mut Header) -> Result<Bytes> {
+        header.origin_size = body.len() as i32;
+        if body.len() < self.compress_threshold {
+            return Ok(body);
+        }
+
+        let mut encoder = ZlibEncoder::new(Vec::with_capacity(body.len() / 2), Compression::default());
+        encoder.write_all(&body)?;
+        let compressed = encoder.finish()?;
+        if compressed.len() >= body.len() {
+            return Ok(body);
+        }
+        header.compress = true;
+        header.compress_size = compressed.len() as i32;
+        Ok(Bytes::from(compressed))
+    }
+}
+
+impl Default for PacketCodec {
+    fn default() -> Self {
+        PacketCodec::new(DEFAULT_MAX_FRAME_SIZE, DEFAULT_COMPRESS_THRESHOLD)
+    }
+}
+
+impl From<&CodecConfig> for PacketCodec {
+    fn from(value: &CodecConfig) -> Self {
+        PacketCodec::new(
+            value.max_frame_size.unwrap_or(DEFAULT_MAX_FRAME_SIZE),
+            value.compress_threshold.unwrap_or(DEFAULT_COMPRESS_THRESHOLD),
+        )
+    }
+}
+
+impl Decoder for PacketCodec {
+    type Item = Packet;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        if src.len() < FRAME_LEN_SIZE {
+            return Ok(None);
+        }
+        let frame_len = u32::from_be_bytes([src[0], src[1], src[2], src[3]]) as usize;
+        if frame_len > self.max_frame_size {
+            return Err(Error::FrameTooLarge(frame_len));
+        }
+        if src.len() < FRAME_LEN_SIZE + frame_len {
+            src.reserve(FRAME_LEN_SIZE + frame_len - src.len());
+            return Ok(None);
+        }
+        src.advance(FRAME_LEN_SIZE);
+        let mut frame = src.split_to(frame_len);
+
+        // 协议头
+        if frame.len() < HEADER_LEN_SIZE {
+            return Err(Error::FrameTooShort(frame.len()));
+        }
+        let header_len = frame.get_u16() as usize;
+        if frame.len() < header_len {
+            return Err(Error::FrameTooShort(frame.len()));
+        }
+        let header = Header::parse_from_bytes(&frame.split_to(header_len))?;
+
+        // 包体
+        let mut body = self.unpack_body(&header, frame.freeze())?;
+        if body.len() < MSG_TYPE_SIZE {
+            return Err(Error::FrameTooShort(body.len()));
+        }
+        let value = body.get_i32();
+        let msg_type = MsgType::from_i32(value).unwrap_or_else(|| {
+            tracing::warn!("unknown msg type {}", value);
+            MsgType::UNKNOWN
+        });
+        Ok(Some(Packet::new(msg_type, body).with_seq(header.seq)))
+    }
+}
+
+impl Encoder<Packet> for PacketCodec {
+    type Error = Error;
+
+    fn encode(&mut self, item: Packet, dst: &mut BytesMut) -> Result<()> {
+        let mut body = BytesMut::with_capacity(MSG_TYPE_SIZE + item.body.len());
+        body.put_i32(item.msg_type.value());
+        body.put_slice(&item.body);
+
+        let mut header = Header::new();
+        header.seq = item.seq;
+        let body = self.pack_body(body.freeze(), &mut header)?;
+        let header = header.write_to_bytes()?;
+
+        let frame_len = HEADER_LEN_SIZE + header.len() + body.len();
+        if frame_len > self.max_frame_size {
+            return Err(Error::FrameTooLarge(frame_len));
+        }
+        dst.reserve(FRAME_LEN_SIZE + frame_len);
+        dst.put_u32(frame_len as u32);
+        dst.put_u16(header.len() as u16);
+        dst.put_slice(&header);
+        dst.put_slice(&body);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按线上格式拼装一帧, 用于构造异常的协议头
+    fn raw_frame(header: &Header, body: &[u8]) -> BytesMut {
+        let header = header.write_to_bytes().unwrap();
+        let mut frame = BytesMut::new();
+        frame.put_u32((HEADER_LEN_SIZE + header.len() + body.len()) as u32);
+        frame.put_u16(header.len() as u16);
+        frame.put_slice(&header);
+        frame.put_slice(body);
+        frame
+    }
+
+    fn compress(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn parse_header(frame: &[u8]) -> Header {
+        let header_len = u16::from_be_bytes([frame[4], frame[5]]) as usize;
+        Header::parse_from_bytes(&frame[6..6 + header_len]).unwrap()
+    }
+
+    fn round_trip(codec: &mut PacketCodec, body: Vec<u8>) -> (Header, Packet) {
+        let packet = Packet::new(MsgType::FIND_USER, Bytes::from(body)).with_seq(7);
+        let mut buf = BytesMut::new();
+        codec.encode(packet, &mut buf).unwrap();
+        let header = parse_header(&buf);
+        let decoded = codec.decode(&mut buf).unwrap().unwrap();
+        assert!(buf.is_empty());
+        (header, decoded)
+    }
+
+    #[test]
+    fn round_trip_small_packet_uncompressed() {
+        let mut codec = PacketCodec::default();
+        let (header, packet) = round_trip(&mut codec, b"hello".to_vec());
+        assert!(!header.compress);
+        assert_eq!(header.origin_size as usize, MSG_TYPE_SIZE + 5);
+        assert_eq!(packet.msg_type, MsgType::FIND_USER);
+        assert_eq!(packet.seq, 7);
+        assert_eq!(packet.body.as_ref(), b"hello");
+    }
+
+    #[test]
+    fn round_trip_large_packet_compressed() {
+        let mut codec = PacketCodec::default();
+        let body = b"lechat".repeat(1024);
+        let (header, packet) = round_trip(&mut codec, body.clone());
+        assert!(header.compress);
+        assert_eq!(header.origin_size as usize, MSG_TYPE_SIZE + body.len());
+        assert!((header.compress_size as usize) < body.len());
+        assert_eq!(packet.msg_type, MsgType::FIND_USER);
+        assert_eq!(packet.body.as_ref(), body.as_slice());
+    }
+
+    #[test]
+    fn incompressible_body_sent_as_is() {
+        let mut codec = PacketCodec::default();
+        let mut seed = 0x2545_f491_u32;
+        let body: Vec<u8> = (0..4096)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                seed as u8
+            })
+            .collect();
+        let (header, packet) = round_trip(&mut codec, body.clone());
+        assert!(!header.compress);
+        assert_eq!(packet.body.as_ref(), body.as_slice());
+    }
+
+    #[test]
+    fn partial_frame_waits_for_more_data() {
+        let mut codec = PacketCodec::default();
+        let mut buf = BytesMut::new();
+        codec.encode(Packet::new(MsgType::HEARTBEAT, Bytes::new()), &mut buf).unwrap();
+        let mut partial = buf.split_to(buf.len() - 1);
+        assert!(codec.decode(&mut partial).unwrap().is_none());
+        partial.unsplit(buf);
+        let packet = codec.decode(&mut partial).unwrap().unwrap();
+        assert_eq!(packet.msg_type, MsgType::HEARTBEAT);
+    }
+
+    #[test]
+    fn reject_oversize_frame() {
+        let mut codec = PacketCodec::new(64, DEFAULT_COMPRESS_THRESHOLD);
+        let mut buf = BytesMut::new();
+        buf.put_u32(65);
+        assert!(matches!(codec.decode(&mut buf), Err(Error::FrameTooLarge(65))));
+
+        let packet = Packet::new(MsgType::FIND_USER, Bytes::from(vec![0u8; 64]));
+        let res = codec.encode(packet, &mut BytesMut::new());
+        assert!(matches!(res, Err(Error::FrameTooLarge(_))));
+    }
+
+    #[test]
+    fn reject_origin_size_larger_than_max_frame() {
+        let mut codec = PacketCodec::new(64, DEFAULT_COMPRESS_THRESHOLD);
+        let mut header = Header::new();
+        header.origin_size = 65;
+        let mut buf = raw_frame(&header, &[0; 8]);
+        assert!(matches!(codec.decode(&mut buf), Err(Error::FrameTooLarge(65))));
+    }
+
+    #[test]
+    fn reject_origin_size_mismatch() {
+        let mut codec = PacketCodec::default();
+        let mut header = Header::new();
+        header.origin_size = 9;
+        let mut buf = raw_frame(&header, &[0; 8]);
+        let res = codec.decode(&mut buf);
+        assert!(matches!(
+            res,
+            Err(Error::SizeMismatch {
+                declared: 9,
+                actual: 8
+            })
+        ));
+    }
+
+    #[test]
+    fn reject_compress_size_mismatch() {
+        let mut codec = PacketCodec::default();
+        let compressed = compress(&[1; 64]);
+        let mut header = Header::new();
+        header.compress = true;
+        header.origin_size = 64;
+        header.compress_size = compressed.len() as i32 + 1;
+        let mut buf = raw_frame(&header, &compressed);
+        assert!(matches!(codec.decode(&mut buf), Err(Error::SizeMismatch { .. })));
+    }
+
+    #[test]
+    fn reject_body_inflating_beyond_origin_size() {
+        let mut codec = PacketCodec::default();
+        let compressed = compress(&vec![0; 1024 * 1024]);
+        let mut header = Header::new();
+        header.compress = true;
+        header.origin_size = 16;
+        header.compress_size = compressed.len() as i32;
+        let mut buf = raw_frame(&header, &compressed);
+        // 解压时只读取origin_size + 1个字节
+        let res = codec.decode(&mut buf);
+        assert!(matches!(
+            res,
+            Err(Error::SizeMismatch {
+                declared: 16,
+                actual: 17
+            })
+        ));
+    }
+}
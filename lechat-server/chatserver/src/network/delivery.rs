@@ -0,0 +1,293 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use fred::prelude::{KeysInterface, ListInterface, PubsubInterface};
+use protobuf::Enum;
+use shaku::{Component, Interface};
+
+use crate::base::config::Config;
+use crate::base::response::{Error, Result};
+use crate::components::redis::{self, IRedisService};
+use crate::network::packet::Packet;
+use crate::network::registry::ISessionRegistry;
+use crate::network::session::{ISessionManager, Session};
+use crate::network::stubs::chatmsg::MsgType;
+
+/// 默认首次重发间隔, 之后每次翻倍
+const DEFAULT_RETRANSMIT_INTERVAL: Duration = Duration::from_secs(2);
+/// 默认最多发送次数, 超过后转存离线
+const DEFAULT_RETRANSMIT_MAX_ATTEMPTS: u32 = 5;
+/// 检查待重发推送的最大间隔
+const RETRANSMIT_TICK: Duration = Duration::from_secs(1);
+
+const OFFLINE_KEY_PREFIX: &str = "offline:msg:";
+const PUSH_CHANNEL_PREFIX: &str = "session:push:";
+/// 离线消息保留时长(秒)
+const OFFLINE_TTL: i64 = 7 * 24 * 3600;
+/// 每个用户最多保留的离线消息数
+const OFFLINE_MAX_LEN: i64 = 1000;
+/// 登录后每批取出的离线消息数
+const OFFLINE_BATCH: usize = 100;
+
+/// 服务端推送投递, 保证至少一次送达
+#[async_trait]
+pub trait IDeliveryService: Interface {
+    /// 可靠推送给用户的全部在线会话, 用户不在线时转存离线
+    async fn deliver(&self, user_id: &str, packet: Packet) -> Result<()>;
+    /// 通知用户的全部在线会话, 不保证送达, 用户不在线时丢弃
+    async fn notify(&self, user_id: &str, packet: Packet) -> Result<()>;
+    /// 处理其他实例转发来的推送
+    async fn push_local(&self, user_id: &str, packet: Packet, reliable: bool);
+    /// 重发到期未确认的推送, 达到最大次数的转存离线
+    async fn retransmit(&self, interval: Duration, max_attempts: u32);
+    /// 连接关闭后将未确认的推送转存离线
+    async fn session_closed(&self, session: &Arc<Session>);
+    /// 登录后向会话投递离线消息
+    async fn deliver_offline(&self, session: &Arc<Session>) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IDeliveryService)]
+pub struct DeliveryServiceImpl {
+    #[shaku(inject)]
+    session_manager: Arc<dyn ISessionManager>,
+    #[shaku(inject)]
+    session_registry: Arc<dyn ISessionRegistry>,
+    #[shaku(inject)]
+    redis_cli: Arc<dyn IRedisService>,
+}
+
+impl DeliveryServiceImpl {
+    /// 推送给本实例上用户的会话, 返回送达的会话数
+    fn send_local(&self, user_id: &str, packet: &Packet, reliable: bool) -> usize {
+        self.session_manager
+            .find_by_user(user_id)
+            .iter()
+            .filter(|s| {
+                if reliable {
+                    s.send_reliable(packet.clone()).is_ok()
+                } else {
+                    s.send(packet.clone()).is_ok()
+                }
+            })
+            .count()
+    }
+
+    /// 推送给用户所在的全部实例, 返回是否有实例接收
+    async fn route(&self, user_id: &str, packet: &Packet, reliable: bool) -> Result<bool> {
+        let mut instances: Vec<String> = self
+            .session_registry
+            .locate(user_id)
+            .await?
+            .into_iter()
+            .map(|(_, instance)| instance)
+            .collect();
+        instances.sort_unstable();
+        instances.dedup();
+
+        let mut received = false;
+        for instance in instances {
+            if instance == self.session_registry.instance_id() {
+                received |= self.send_local(user_id, packet, reliable) > 0;
+                continue;
+            }
+            let channel = format!("{PUSH_CHANNEL_PREFIX}{instance}");
+            let receivers: i64 = self
+                .redis_cli
+                .get_conn()
+                .publish(channel, encode_push(user_id, packet, reliable))
+                .await
+                .map_err(|err| {
+                    tracing::error!(
+                        "publish push of user {} to instance {} failed, {err:#}",
+                        user_id,
+                        instance
+                    );
+                    Error::InternalServerError
+                })?;
+            received |= receivers > 0;
+        }
+        Ok(received)
+    }
+
+    async fn store_offline(&self, user_id: &str, packets: Vec<Packet>) -> Result<()> {
+        if packets.is_empty() {
+            return Ok(());
+        }
+        let key = format!("{OFFLINE_KEY_PREFIX}{user_id}");
+        let count = packets.len();
+        let values: Vec<Bytes> = packets.iter().map(encode_offline).collect();
+        let redis_cli = self.redis_cli.get_conn();
+        let res: fred::prelude::RedisResult<()> = async {
+            redis_cli.rpush::<(), _, _>(&key, values).await?;
+            redis_cli.ltrim::<(), _>(&key, -OFFLINE_MAX_LEN, -1).await?;
+            redis_cli.expire::<(), _>(&key, OFFLINE_TTL).await
+        }
+        .await;
+        res.map_err(|err| {
+            tracing::error!(
+                "store {} offline messages for user {} failed, {err:#}",
+                count,
+                user_id
+            );
+            Error::InternalServerError
+        })?;
+        tracing::info!("store {} offline messages for user {}", count, user_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl IDeliveryService for DeliveryServiceImpl {
+    async fn deliver(&self, user_id: &str, packet: Packet) -> Result<()> {
+        if !self.route(user_id, &packet, true).await? {
+            self.store_offline(user_id, vec![packet]).await?;
+        }
+        Ok(())
+    }
+
+    async fn notify(&self, user_id: &str, packet: Packet) -> Result<()> {
+        self.route(user_id, &packet, false).await.map(|_| ())
+    }
+
+    async fn push_local(&self, user_id: &str, packet: Packet, reliable: bool) {
+        // 会话在转发途中已断开时, 可靠推送转存离线
+        if self.send_local(user_id, &packet, reliable) == 0 && reliable {
+            let _ = self.store_offline(user_id, vec![packet]).await;
+        }
+    }
+
+    async fn retransmit(&self, interval: Duration, max_attempts: u32) {
+        for session in self.session_manager.sessions() {
+            let expired = session.retransmit(interval, max_attempts);
+            if expired.is_empty() {
+                continue;
+            }
+            match session.user() {
+                Some(user) => {
+                    let _ = self.store_offline(&user.user_id, expired).await;
+                }
+                None => tracing::warn!(
+                    "drop {} pushes of anonymous session {}",
+                    expired.len(),
+                    session.id()
+                ),
+            }
+        }
+    }
+
+    async fn session_closed(&self, session: &Arc<Session>) {
+        let pending = session.take_pending();
+        if pending.is_empty() {
+            return;
+        }
+        match session.user() {
+            Some(user) => {
+                let _ = self.store_offline(&user.user_id, pending).await;
+            }
+            None => tracing::warn!(
+                "drop {} pushes of anonymous session {}",
+                pending.len(),
+                session.id()
+            ),
+        }
+    }
+
+    async fn deliver_offline(&self, session: &Arc<Session>) -> Result<()> {
+        let Some(user) = session.user() else {
+            return Err(Error::NotLogin);
+        };
+        let key = format!("{OFFLINE_KEY_PREFIX}{}", user.user_id);
+        let redis_cli = self.redis_cli.get_conn();
+        loop {
+            let values: Vec<Bytes> = redis_cli.lpop(&key, Some(OFFLINE_BATCH)).await.map_err(|err| {
+                tracing::error!("load offline messages of user {} failed, {err:#}", user.user_id);
+                Error::InternalServerError
+            })?;
+            let count = values.len();
+            let mut packets = values.into_iter().filter_map(decode_offline);
+            for packet in packets.by_ref() {
+                if session.send_reliable(packet.clone()).is_err() {
+                    // 连接已断开, 剩余消息放回离线队列
+                    let rest = std::iter::once(packet).chain(packets).collect();
+                    return self.store_offline(&user.user_id, rest).await;
+                }
+            }
+            if count < OFFLINE_BATCH {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// 离线消息存储格式: | msg_type(i32) | body |
+fn encode_offline(packet: &Packet) -> Bytes {
+    let mut buf = BytesMut::with_capacity(4 + packet.body.len());
+    buf.put_i32(packet.msg_type.value());
+    buf.put_slice(&packet.body);
+    buf.freeze()
+}
+
+fn decode_offline(mut value: Bytes) -> Option<Packet> {
+    if value.len() < 4 {
+        return None;
+    }
+    let msg_type = MsgType::from_i32(value.get_i32())?;
+    Some(Packet::new(msg_type, value))
+}
+
+/// 实例间转发格式: | reliable(u8) | user_id_len(u16) | user_id | msg_type(i32) | body |
+fn encode_push(user_id: &str, packet: &Packet, reliable: bool) -> Bytes {
+    let mut buf = BytesMut::with_capacity(3 + user_id.len() + 4 + packet.body.len());
+    buf.put_u8(reliable as u8);
+    buf.put_u16(user_id.len() as u16);
+    buf.put_slice(user_id.as_bytes());
+    buf.put_slice(&encode_offline(packet));
+    buf.freeze()
+}
+
+fn decode_push(mut value: Bytes) -> Option<(String, Packet, bool)> {
+    if value.len() < 3 {
+        return None;
+    }
+    let reliable = value.get_u8() != 0;
+    let len = value.get_u16() as usize;
+    if value.len() < len {
+        return None;
+    }
+    let user_id = String::from_utf8(value.split_to(len).to_vec()).ok()?;
+    Some((user_id, decode_offline(value)?, reliable))
+}
+
+/// 订阅其他实例转发到本实例的推送
+pub async fn listen_push(
+    delivery: Arc<dyn IDeliveryService>,
+    registry: Arc<dyn ISessionRegistry>,
+    redis_cli: Arc<dyn IRedisService>,
+) {
+    let channel = format!("{PUSH_CHANNEL_PREFIX}{}", registry.instance_id());
+    redis::subscribe(redis_cli.get_conn(), channel, |value| {
+        let delivery = delivery.clone();
+        async move {
+            let push = value.as_bytes().map(Bytes::copy_from_slice).and_then(decode_push);
+            match push {
+                Some((user_id, packet, reliable)) => delivery.push_local(&user_id, packet, reliable).await,
+                None => tracing::warn!("invalid push notification"),
+            }
+        }
+    })
+    .await
+}
+
+/// 定期重发未确认的推送
+pub async fn retransmit_pushes(delivery: Arc<dyn IDeliveryService>, cfg: Arc<Config>) {
+    let interval = cfg.retransmit.interval.unwrap_or(DEFAULT_RETRANSMIT_INTERVAL);
+    let max_attempts = cfg.retransmit.max_attempts.unwrap_or(DEFAULT_RETRANSMIT_MAX_ATTEMPTS).max(1);
+    let mut ticker = tokio::time::interval(interval.min(RETRANSMIT_TICK));
+    loop {
+        ticker.tick().await;
+        delivery.retransmit(interval, max_attempts).await;
+    }
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+
+use crate::base::response::Error;
+use crate::network::handler::{
+    IAckHandler, IChatHandler, IFindUserHandler, IGetFriendListHandler, IHandshakeHandler, IHeartbeatHandler,
+    ILoginHandler, IModifyPasswordHandler, IOperateFriendHandler, IUpdateTeamInfoHandler,
+    IUpdateUserInfoHandler,
+};
+use crate::network::packet::Packet;
+use crate::network::session::Session;
+use crate::network::stubs::chatmsg::{ErrorCode, ErrorReply, MsgType};
+
+/// 长连接命令处理器, 每种`MsgType`对应一个实现
+#[async_trait]
+pub trait ICommandHandler: Send + Sync {
+    /// 处理请求, 返回需要回复给客户端的数据包, 出错时由分发器回复通用错误应答
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error>;
+}
+
+/// 注入的处理器接口以`Arc`持有, 通过该实现统一转换为`ICommandHandler`
+#[async_trait]
+impl<T: ICommandHandler + ?Sized> ICommandHandler for Arc<T> {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        (**self).handle(session, packet).await
+    }
+}
+
+/// 长连接命令分发器, TCP网关与WebSocket共用
+#[async_trait]
+pub trait ICommandDispatcher: Interface {
+    /// 按消息类型分发请求, 返回需要回复给客户端的数据包
+    async fn dispatch(&self, session: &Arc<Session>, packet: Packet) -> Option<Packet>;
+}
+
+/// 命令注册表, 新增命令时注入对应的处理器并在`handler`中登记
+#[derive(Component)]
+#[shaku(interface = ICommandDispatcher)]
+pub struct CommandDispatcherImpl {
+    #[shaku(inject)]
+    handshake: Arc<dyn IHandshakeHandler>,
+    #[shaku(inject)]
+    heartbeat: Arc<dyn IHeartbeatHandler>,
+    #[shaku(inject)]
+    login: Arc<dyn ILoginHandler>,
+    #[shaku(inject)]
+    ack: Arc<dyn IAckHandler>,
+    #[shaku(inject)]
+    chat: Arc<dyn IChatHandler>,
+    #[shaku(inject)]
+    modify_password: Arc<dyn IModifyPasswordHandler>,
+    #[shaku(inject)]
+    update_user_info: Arc<dyn IUpdateUserInfoHandler>,
+    #[shaku(inject)]
+    find_user: Arc<dyn IFindUserHandler>,
+    #[shaku(inject)]
+    operate_friend: Arc<dyn IOperateFriendHandler>,
+    #[shaku(inject)]
+    get_friend_list: Arc<dyn IGetFriendListHandler>,
+    #[shaku(inject)]
+    update_team_info: Arc<dyn IUpdateTeamInfoHandler>,
+}
+
+impl CommandDispatcherImpl {
+    fn handler(&self, msg_type: MsgType) -> Option<&dyn ICommandHandler> {
+        let handler: &dyn ICommandHandler = match msg_type {
+            MsgType::HANDSHAKE => &self.handshake,
+            MsgType::HEARTBEAT => &self.heartbeat,
+            MsgType::LOGIN => &self.login,
+            MsgType::ACK => &self.ack,
+            MsgType::CHAT => &self.chat,
+            MsgType::MODIFY_PASSWORD => &self.modify_password,
+            MsgType::UPDATE_USER_INFO => &self.update_user_info,
+            MsgType::FIND_USER => &self.find_user,
+            MsgType::OPERATE_FRIEND => &self.operate_friend,
+            MsgType::GET_OFRIEND_LIST => &self.get_friend_list,
+            MsgType::UPDATE_TEAM_INFO => &self.update_team_info,
+            _ => return None,
+        };
+        Some(handler)
+    }
+}
+
+#[async_trait]
+impl ICommandDispatcher for CommandDispatcherImpl {
+    async fn dispatch(&self, session: &Arc<Session>, packet: Packet) -> Option<Packet> {
+        session.touch();
+        let (msg_type, seq) = (packet.msg_type, packet.seq);
+        if session.client().is_none() && !matches!(msg_type, MsgType::HANDSHAKE | MsgType::HEARTBEAT) {
+            // 未握手的客户端不支持版本协商, 视为版本过低
+            tracing::warn!("{:?} from {} before handshake", msg_type, session.peer());
+            session.close();
+            return error_reply(
+                msg_type,
+                ErrorCode::ERR_TOO_OLD_VER,
+                "客户端版本过低, 请升级后重试",
+            )
+            .map(|p| p.with_seq(seq));
+        }
+        let Some(handler) = self.handler(msg_type) else {
+            tracing::warn!("unsupported msg type {:?} from {}", msg_type, session.peer());
+            return error_reply(msg_type, ErrorCode::ERR_UNKNOWN, "不支持的命令").map(|p| p.with_seq(seq));
+        };
+        let reply = match handler.handle(session, packet).await {
+            Ok(reply) => reply,
+            Err(err) => {
+                tracing::warn!("handle {:?} from {} failed, {err}", msg_type, session.peer());
+                error_reply(msg_type, error_code(&err), &err.to_string())
+            }
+        };
+        // 应答携带请求的序列号, 便于客户端对应
+        reply.map(|p| p.with_seq(seq))
+    }
+}
+
+/// 构造通用错误应答
+pub fn error_reply(msg_type: MsgType, code: ErrorCode, msg: &str) -> Option<Packet> {
+    let mut reply = ErrorReply::new();
+    reply.msg_type = msg_type.into();
+    reply.code = code.into();
+    reply.msg = msg.to_string();
+    Packet::from_message(MsgType::ERROR, &reply)
+        .map_err(|err| tracing::error!("encode error reply failed, {err:#}"))
+        .ok()
+}
+
+/// 业务错误对应的协议错误码
+pub fn error_code(err: &Error) -> ErrorCode {
+    match err {
+        Error::UserNotRegistered => ErrorCode::ERR_NOT_REG,
+        Error::UserNameOrPasswordMismatch => ErrorCode::ERR_INVALID_PSW,
+        Error::PasswordMismatch => ErrorCode::ERR_MODIFY_PSW_FAIL,
+        Error::UsernameDuplicate | Error::PhoneDuplicate | Error::EmailDuplicate => {
+            ErrorCode::ERR_REG_ALREADY
+        }
+        Error::NotLogin | Error::TokenInvalid => ErrorCode::ERR_NOT_LOGIN,
+        Error::TeamNameDuplicate => ErrorCode::ERR_GROUPNAME_EXIST,
+        Error::InternalServerError
+        | Error::ParamInvalid(_)
+        | Error::VerifyCodeInvalid
+        | Error::TooManyRequests
+        | Error::AccountLocked(_)
+        | Error::AccountNotVerified
+        | Error::AlreadyFriend
+        | Error::NotFriend => ErrorCode::ERR_UNKNOWN,
+    }
+}
@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("connection io error, {0}")]
+    Io(#[from] std::io::Error),
+    #[error("frame is too short, {0} bytes")]
+    FrameTooShort(usize),
+    #[error("frame is too large, {0} bytes")]
+    FrameTooLarge(usize),
+    #[error("declared size {declared} mismatch actual size {actual}")]
+    SizeMismatch { declared: i64, actual: usize },
+    #[error("protobuf message is invalid, {0}")]
+    Protobuf(#[from] protobuf::Error),
+    #[error("session {0} is closed")]
+    SessionClosed(u64),
+}
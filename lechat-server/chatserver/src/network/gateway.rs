@@ -0,0 +1,118 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use futures::{SinkExt, StreamExt};
+use shaku::HasComponent;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tokio_util::codec::Framed;
+
+use crate::base::config::Config;
+use crate::base::tls;
+use crate::components::Modules;
+use crate::network::codec::PacketCodec;
+use crate::network::dispatcher::ICommandDispatcher;
+use crate::network::error::Result;
+use crate::network::session::{self, ISessionManager, Outbound};
+use crate::Error;
+
+/// 客户端长连接网关, 监听配置中的`client`地址
+pub struct Gateway {
+    listener: TcpListener,
+    codec: PacketCodec,
+    modules: Arc<Modules>,
+    tls_acceptor: Option<TlsAcceptor>,
+}
+
+impl Gateway {
+    pub async fn bind(cfg: &Config, modules: Arc<Modules>) -> crate::Result<Self> {
+        let addr = format!("{}:{}", cfg.client.ip, cfg.client.port);
+        let tls_acceptor = match &cfg.client.tls {
+            Some(tls) => Some(TlsAcceptor::from(Arc::new(tls::server_config(tls)?))),
+            None => None,
+        };
+        let listener = TcpListener::bind(&addr).await.map_err(|e| Error::ServerError(e.to_string()))?;
+        tracing::info!(
+            "client gateway starts listening on {}, tls: {}",
+            addr,
+            tls_acceptor.is_some()
+        );
+        Ok(Gateway {
+            listener,
+            codec: PacketCodec::from(&cfg.codec),
+            modules,
+            tls_acceptor,
+        })
+    }
+
+    pub async fn run(self) {
+        loop {
+            let (stream, peer) = match self.listener.accept().await {
+                Ok(v) => v,
+                Err(err) => {
+                    tracing::error!("accept client connection failed, {err:#}");
+                    continue;
+                }
+            };
+            let codec = self.codec.clone();
+            let modules = self.modules.clone();
+            let tls_acceptor = self.tls_acceptor.clone();
+            tokio::spawn(async move {
+                let res = match tls_acceptor {
+                    Some(acceptor) => match acceptor.accept(stream).await {
+                        Ok(stream) => serve(modules, codec, stream, peer).await,
+                        Err(err) => {
+                            tracing::warn!("tls handshake with {} failed, {err:#}", peer);
+                            return;
+                        }
+                    },
+                    None => serve(modules, codec, stream, peer).await,
+                };
+                if let Err(err) = res {
+                    tracing::warn!("connection {} closed with error, {err:#}", peer);
+                }
+            });
+        }
+    }
+}
+
+/// 处理单个连接: 读取数据包并分发, 同时将会话中的待发数据写回客户端
+async fn serve<S>(modules: Arc<Modules>, codec: PacketCodec, stream: S, peer: SocketAddr) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let session_manager: Arc<dyn ISessionManager> = modules.resolve();
+    let dispatcher: Arc<dyn ICommandDispatcher> = modules.resolve();
+    let (session, mut rx) = session_manager.create(peer);
+    let mut framed = Framed::new(stream, codec);
+
+    let res = loop {
+        tokio::select! {
+            frame = framed.next() => {
+                let packet = match frame {
+                    Some(Ok(packet)) => packet,
+                    Some(Err(err)) => break Err(err),
+                    None => break Ok(()),
+                };
+                if let Some(reply) = dispatcher.dispatch(&session, packet).await {
+                    if let Err(err) = framed.send(reply).await {
+                        break Err(err);
+                    }
+                }
+            }
+            out = rx.recv() => match out {
+                Some(Outbound::Packet(packet)) => {
+                    if let Err(err) = framed.send(packet).await {
+                        break Err(err);
+                    }
+                }
+                Some(Outbound::Close) | None => break Ok(()),
+            }
+        }
+    };
+
+    session::close_session(&modules, &session).await;
+    tracing::info!("connection {} closed", peer);
+    res
+}
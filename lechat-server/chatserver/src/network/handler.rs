@@ -0,0 +1,751 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use library::utils;
+use protobuf::{EnumFull, MessageField};
+use shaku::{Component, Interface};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::base::config::Config;
+use crate::base::response::Error;
+use crate::network::delivery::IDeliveryService;
+use crate::network::dispatcher::{error_code, ICommandHandler};
+use crate::network::packet::Packet;
+use crate::network::registry::ISessionRegistry;
+use crate::network::session::{ClientInfo, ISessionManager, Session, SessionUser};
+use crate::network::stubs::chatmsg::{
+    ClientType, ErrorCode, FriendOperationApplyType, FriendOperationType, GroupOperationType, Handshake,
+    HandshakeReply, MsgType, OnlineStatus,
+};
+use crate::network::stubs::user as net;
+use crate::network::stubs::{chat, friend};
+use crate::service::friend::{FriendApplyInfo, FriendApplyRequest, FriendInfo, FriendTeam, IFriendService};
+use crate::service::friend_team::IFriendTeamService;
+use crate::service::presence::IPresenceService;
+use crate::service::token::AuthUser;
+use crate::service::user::{
+    self, IUserService, MobileSignInRequest, ModifyPasswordRequest, PublicProfile, SearchType,
+    SearchUserRequest, SignInInfo, SignInRequest, UpdateUserInfoRequest, UserInfo,
+};
+
+/// 当前协议版本
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// 握手处理器
+pub trait IHandshakeHandler: ICommandHandler + Interface {}
+
+/// 心跳处理器
+pub trait IHeartbeatHandler: ICommandHandler + Interface {}
+
+/// 登录处理器
+pub trait ILoginHandler: ICommandHandler + Interface {}
+
+/// 推送确认处理器
+pub trait IAckHandler: ICommandHandler + Interface {}
+
+/// 单聊消息处理器
+pub trait IChatHandler: ICommandHandler + Interface {}
+
+/// 修改密码处理器
+pub trait IModifyPasswordHandler: ICommandHandler + Interface {}
+
+/// 修改资料处理器
+pub trait IUpdateUserInfoHandler: ICommandHandler + Interface {}
+
+/// 搜索用户处理器
+pub trait IFindUserHandler: ICommandHandler + Interface {}
+
+/// 好友操作处理器
+pub trait IOperateFriendHandler: ICommandHandler + Interface {}
+
+/// 好友列表处理器
+pub trait IGetFriendListHandler: ICommandHandler + Interface {}
+
+/// 好友分组处理器
+pub trait IUpdateTeamInfoHandler: ICommandHandler + Interface {}
+
+#[derive(Component)]
+#[shaku(interface = IHandshakeHandler)]
+pub struct HandshakeHandlerImpl {
+    #[shaku(no_default)]
+    cfg: Arc<Config>,
+}
+
+impl IHandshakeHandler for HandshakeHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for HandshakeHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        let req = packet
+            .parse::<Handshake>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        let client_type = req
+            .client_type
+            .enum_value()
+            .map_err(|v| Error::ParamInvalid(format!("未知的设备类型 {v}")))?;
+        let protocol = &self.cfg.protocol;
+        let min_version = protocol
+            .client_min_version
+            .get(&client_type.descriptor().name().to_lowercase())
+            .copied()
+            .or(protocol.min_version)
+            .unwrap_or(PROTOCOL_VERSION);
+        let max_version = protocol.max_version.unwrap_or(PROTOCOL_VERSION);
+        tracing::info!(
+            "handshake from {}, client type: {:?}, version: {}, client version: {}",
+            session.peer(),
+            client_type,
+            req.version,
+            req.client_version
+        );
+
+        let mut reply = HandshakeReply::new();
+        reply.server_version = PROTOCOL_VERSION;
+        reply.min_version = min_version;
+        if req.version < min_version {
+            reply.code = ErrorCode::ERR_TOO_OLD_VER.into();
+            reply.msg = "客户端版本过低, 请升级后重试".to_string();
+            reply.upgrade_url = protocol.upgrade_url.clone().unwrap_or_default();
+            session.close();
+        } else if req.version > max_version {
+            reply.code = ErrorCode::ERR_UNKNOWN.into();
+            reply.msg = "服务端暂不支持该协议版本".to_string();
+            session.close();
+        } else {
+            reply.code = ErrorCode::ERR_OK.into();
+            session.set_client(ClientInfo {
+                version: req.version,
+                client_type,
+            });
+        }
+        Packet::from_message(MsgType::HANDSHAKE, &reply).map(Some).map_err(|err| {
+            tracing::error!("encode handshake reply failed, {err:#}");
+            Error::InternalServerError
+        })
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IHeartbeatHandler)]
+pub struct HeartbeatHandlerImpl;
+
+impl IHeartbeatHandler for HeartbeatHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for HeartbeatHandlerImpl {
+    async fn handle(&self, _session: &Arc<Session>, _packet: Packet) -> Result<Option<Packet>, Error> {
+        Ok(Some(Packet::new(MsgType::HEARTBEAT, Bytes::new())))
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = ILoginHandler)]
+pub struct LoginHandlerImpl {
+    #[shaku(inject)]
+    user_service: Arc<dyn IUserService>,
+    #[shaku(inject)]
+    session_manager: Arc<dyn ISessionManager>,
+    #[shaku(inject)]
+    session_registry: Arc<dyn ISessionRegistry>,
+    #[shaku(inject)]
+    delivery: Arc<dyn IDeliveryService>,
+    #[shaku(inject)]
+    presence: Arc<dyn IPresenceService>,
+}
+
+impl ILoginHandler for LoginHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for LoginHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        let mut reply = net::LoginReply::new();
+        match self.login(session, packet).await {
+            Ok(info) => {
+                reply.code = ErrorCode::ERR_OK.into();
+                reply.user_id = info.user.base_info.user_id;
+                reply.access_token = info.token.access_token;
+                reply.refresh_token = info.token.refresh_token;
+                reply.expires_in = info.token.expires_in;
+                // 离线消息在登录应答之后写出
+                let _ = self.delivery.deliver_offline(session).await;
+            }
+            Err(err) => {
+                reply.code = error_code(&err).into();
+                reply.msg = err.to_string();
+            }
+        }
+        Packet::from_message(MsgType::LOGIN, &reply).map(Some).map_err(|err| {
+            tracing::error!("encode login reply failed, {err:#}");
+            Error::InternalServerError
+        })
+    }
+}
+
+impl LoginHandlerImpl {
+    async fn login(&self, session: &Arc<Session>, packet: Packet) -> Result<SignInInfo, Error> {
+        let req = packet
+            .parse::<net::LoginRequest>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        let client_type = req.client_type.enum_value_or_default();
+        let online_status = req.status.enum_value_or_default();
+        let info = match req.login_type {
+            Some(net::login_request::Login_type::Custom(login)) => {
+                let signin_req = SignInRequest {
+                    username: login.username,
+                    password: login.password,
+                    client_type: client_type.into(),
+                    online_status: online_status.into(),
+                };
+                if let Err(err) = signin_req.validate() {
+                    return Err(Error::ParamInvalid(err.to_string()));
+                }
+                self.user_service.sign_in(signin_req, &session.peer().ip().to_string()).await?
+            }
+            Some(net::login_request::Login_type::Mobile(login)) => {
+                let signin_req = MobileSignInRequest {
+                    mobile: login.mobile,
+                    code: format!("{:06}", login.code),
+                    client_type: client_type.into(),
+                    online_status: online_status.into(),
+                };
+                if let Err(err) = signin_req.validate() {
+                    return Err(Error::ParamInvalid(err.to_string()));
+                }
+                self.user_service.sign_in_by_mobile(signin_req).await?
+            }
+            None => return Err(Error::ParamInvalid("请选择密码或验证码登录".to_string())),
+        };
+        let user = SessionUser {
+            user_id: info.user.base_info.user_id.clone(),
+            client_type,
+            status: online_status,
+        };
+        self.session_manager.bind(session, user.clone());
+        // 同一设备类型上的旧会话会被踢下线
+        self.session_registry.register(session, &user).await?;
+        // 隐身登录对好友显示为离线, 无需通知
+        if user.status != OnlineStatus::INVISIBLE {
+            if let Err(err) = self.presence.notify_status(&user.user_id, client_type, user.status).await {
+                tracing::error!("notify online of user {} failed, {err:#}", user.user_id);
+            }
+        }
+        Ok(info)
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IAckHandler)]
+pub struct AckHandlerImpl;
+
+impl IAckHandler for AckHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for AckHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        if !session.ack(packet.seq) {
+            tracing::debug!(
+                "session {} acknowledged unknown push {}",
+                session.id(),
+                packet.seq
+            );
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IChatHandler)]
+pub struct ChatHandlerImpl {
+    #[shaku(inject)]
+    delivery: Arc<dyn IDeliveryService>,
+    #[shaku(inject)]
+    friend_service: Arc<dyn IFriendService>,
+}
+
+impl IChatHandler for ChatHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for ChatHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        let Some(sender) = session.user() else {
+            return Err(Error::NotLogin);
+        };
+        let mut msg = packet
+            .parse::<chat::ChatMessage>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        if msg.target_id.is_empty() {
+            return Err(Error::ParamInvalid("接收方不能为空".to_string()));
+        }
+        msg.msg_id = Uuid::new_v4().to_string();
+        msg.sender_id = sender.user_id;
+        msg.create_time = utils::time::now_timestamp_millis() as i64;
+        if self.friend_service.can_chat(&msg.sender_id, &msg.target_id).await? {
+            let push = Packet::from_message(MsgType::CHAT, &msg).map_err(|err| {
+                tracing::error!("encode chat message failed, {err:#}");
+                Error::InternalServerError
+            })?;
+            self.delivery.deliver(&msg.target_id, push).await?;
+        }
+
+        let mut reply = chat::ChatReply::new();
+        reply.code = ErrorCode::ERR_OK.into();
+        reply.msg_id = msg.msg_id;
+        Packet::from_message(MsgType::CHAT, &reply).map(Some).map_err(|err| {
+            tracing::error!("encode chat reply failed, {err:#}");
+            Error::InternalServerError
+        })
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IModifyPasswordHandler)]
+pub struct ModifyPasswordHandlerImpl {
+    #[shaku(inject)]
+    user_service: Arc<dyn IUserService>,
+}
+
+impl IModifyPasswordHandler for ModifyPasswordHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for ModifyPasswordHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        let Some(user) = session.user() else {
+            return Err(Error::NotLogin);
+        };
+        let req = packet
+            .parse::<net::ModifyPasswordRequest>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        let modify_req = ModifyPasswordRequest {
+            old_password: req.old_password,
+            new_password: req.new_password,
+        };
+        let auth_user = AuthUser {
+            user_id: user.user_id,
+            client_type: user.client_type.into(),
+        };
+        let res = match modify_req.validate() {
+            Ok(_) => {
+                self.user_service
+                    .modify_password(&auth_user, modify_req, Some(session.peer().ip().to_string()))
+                    .await
+            }
+            Err(err) => Err(Error::ParamInvalid(err.to_string())),
+        };
+
+        let mut reply = net::ModifyPasswordReply::new();
+        match res {
+            Ok(_) => reply.code = ErrorCode::ERR_OK.into(),
+            Err(err) => {
+                reply.code = match err {
+                    Error::ParamInvalid(_) => ErrorCode::ERR_MODIFY_PSW_FAIL,
+                    _ => error_code(&err),
+                }
+                .into();
+                reply.msg = err.to_string();
+            }
+        }
+        Packet::from_message(MsgType::MODIFY_PASSWORD, &reply).map(Some).map_err(|err| {
+            tracing::error!("encode modify password reply failed, {err:#}");
+            Error::InternalServerError
+        })
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IUpdateUserInfoHandler)]
+pub struct UpdateUserInfoHandlerImpl {
+    #[shaku(inject)]
+    user_service: Arc<dyn IUserService>,
+}
+
+impl IUpdateUserInfoHandler for UpdateUserInfoHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for UpdateUserInfoHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        let Some(user) = session.user() else {
+            return Err(Error::NotLogin);
+        };
+        let req = packet
+            .parse::<net::UpdateUserInfoRequest>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        let update_req = UpdateUserInfoRequest {
+            nickname: req.nickname,
+            gender: req.gender.map(|v| v.enum_value_or_default().into()),
+            birthday: req.birthday,
+            signature: req.signature,
+            address: req.address,
+            email: req.email,
+            facetype: req.facetype,
+            customface: req.customface,
+            phone_searchable: req.phone_searchable,
+        };
+        let res = match update_req.validate() {
+            Ok(_) => self.user_service.update_user_info(&user.user_id, update_req).await,
+            Err(err) => Err(Error::ParamInvalid(err.to_string())),
+        };
+
+        let mut reply = net::UpdateUserInfoReply::new();
+        match res {
+            Ok(info) => {
+                reply.code = ErrorCode::ERR_OK.into();
+                reply.profile = MessageField::some((&info).into());
+            }
+            Err(err) => {
+                reply.code = match err {
+                    Error::ParamInvalid(_) | Error::EmailDuplicate => ErrorCode::ERR_UPD_USERINFO_FAIL,
+                    _ => error_code(&err),
+                }
+                .into();
+                reply.msg = err.to_string();
+            }
+        }
+        Packet::from_message(MsgType::UPDATE_USER_INFO, &reply)
+            .map(Some)
+            .map_err(|err| {
+                tracing::error!("encode update user info reply failed, {err:#}");
+                Error::InternalServerError
+            })
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IFindUserHandler)]
+pub struct FindUserHandlerImpl {
+    #[shaku(inject)]
+    user_service: Arc<dyn IUserService>,
+}
+
+impl IFindUserHandler for FindUserHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for FindUserHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        if session.user().is_none() {
+            return Err(Error::NotLogin);
+        }
+        let req = packet
+            .parse::<net::FindUserRequest>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        let search_type = match req.type_.enum_value_or_default() {
+            net::FindUserType::FIND_BY_USER_ID => SearchType::UserId,
+            net::FindUserType::FIND_BY_USERNAME => SearchType::Username,
+            net::FindUserType::FIND_BY_PHONE => SearchType::Phone,
+            net::FindUserType::FIND_BY_NICKNAME => SearchType::Nickname,
+        };
+        let search_req = SearchUserRequest {
+            search_type,
+            keyword: req.keyword,
+            page: if req.page == 0 { 1 } else { req.page as u64 },
+            page_size: if req.page_size == 0 {
+                20
+            } else {
+                req.page_size as u64
+            },
+        };
+        if let Err(err) = search_req.validate() {
+            return Err(Error::ParamInvalid(err.to_string()));
+        }
+        let res = self.user_service.search_users(search_req).await?;
+
+        let mut reply = net::FindUserReply::new();
+        reply.code = ErrorCode::ERR_OK.into();
+        reply.total = res.total;
+        reply.users = res.users.iter().map(Into::into).collect();
+        Packet::from_message(MsgType::FIND_USER, &reply).map(Some).map_err(|err| {
+            tracing::error!("encode find user reply failed, {err:#}");
+            Error::InternalServerError
+        })
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IOperateFriendHandler)]
+pub struct OperateFriendHandlerImpl {
+    #[shaku(inject)]
+    friend_service: Arc<dyn IFriendService>,
+}
+
+impl IOperateFriendHandler for OperateFriendHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for OperateFriendHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        let Some(user) = session.user() else {
+            return Err(Error::NotLogin);
+        };
+        let req = packet
+            .parse::<friend::OperateFriendRequest>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        let operation = req.type_.enum_value_or_default();
+        let mut reply = friend::OperateFriendReply::new();
+        reply.type_ = operation.into();
+        let res = match operation {
+            FriendOperationType::SEND_ADD_APPLY => {
+                let apply_req = FriendApplyRequest {
+                    user_id: req.user_id,
+                    message: Some(req.message).filter(|m| !m.is_empty()),
+                };
+                match apply_req.validate() {
+                    Ok(_) => self.friend_service.apply(&user.user_id, apply_req).await,
+                    Err(err) => Err(Error::ParamInvalid(err.to_string())),
+                }
+                .map(|apply| reply.apply = MessageField::some((&apply).into()))
+            }
+            FriendOperationType::REPLY_ADD_APPLY => self
+                .friend_service
+                .reply_apply(&user.user_id, req.apply_id, req.accept.enum_value_or_default())
+                .await
+                .map(|apply| reply.apply = MessageField::some((&apply).into())),
+            FriendOperationType::SEND_DELETE_APPLY => self
+                .friend_service
+                .delete_friend(&user.user_id, &req.user_id)
+                .await
+                .map(|profile| reply.user = MessageField::some((&profile).into())),
+            _ => Err(Error::ParamInvalid("不支持的好友操作".to_string())),
+        };
+
+        match res {
+            Ok(_) => reply.code = ErrorCode::ERR_OK.into(),
+            Err(err) => {
+                reply.code = error_code(&err).into();
+                reply.msg = err.to_string();
+            }
+        }
+        Packet::from_message(MsgType::OPERATE_FRIEND, &reply).map(Some).map_err(|err| {
+            tracing::error!("encode operate friend reply failed, {err:#}");
+            Error::InternalServerError
+        })
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IGetFriendListHandler)]
+pub struct GetFriendListHandlerImpl {
+    #[shaku(inject)]
+    friend_service: Arc<dyn IFriendService>,
+}
+
+impl IGetFriendListHandler for GetFriendListHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for GetFriendListHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        let Some(user) = session.user() else {
+            return Err(Error::NotLogin);
+        };
+        let req = packet
+            .parse::<friend::GetFriendListRequest>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        let list = self.friend_service.list_friends(&user.user_id, Some(req.since)).await?;
+
+        let mut reply = friend::GetFriendListReply::new();
+        reply.code = ErrorCode::ERR_OK.into();
+        reply.version = list.version;
+        reply.teams = list.teams.iter().map(Into::into).collect();
+        reply.removed = list.removed;
+        Packet::from_message(MsgType::GET_OFRIEND_LIST, &reply)
+            .map(Some)
+            .map_err(|err| {
+                tracing::error!("encode friend list reply failed, {err:#}");
+                Error::InternalServerError
+            })
+    }
+}
+
+#[derive(Component)]
+#[shaku(interface = IUpdateTeamInfoHandler)]
+pub struct UpdateTeamInfoHandlerImpl {
+    #[shaku(inject)]
+    team_service: Arc<dyn IFriendTeamService>,
+}
+
+impl IUpdateTeamInfoHandler for UpdateTeamInfoHandlerImpl {}
+
+#[async_trait]
+impl ICommandHandler for UpdateTeamInfoHandlerImpl {
+    async fn handle(&self, session: &Arc<Session>, packet: Packet) -> Result<Option<Packet>, Error> {
+        let Some(user) = session.user() else {
+            return Err(Error::NotLogin);
+        };
+        let req = packet
+            .parse::<friend::UpdateTeamInfoRequest>()
+            .map_err(|err| Error::ParamInvalid(err.to_string()))?;
+        let operation = req.type_.enum_value_or_default();
+        let user_id = &user.user_id;
+        let res = match operation {
+            GroupOperationType::GROUP_OPERATION_ADD => self.team_service.add_team(user_id, &req.name).await,
+            GroupOperationType::GROUP_OPERATION_DELETE => {
+                self.team_service.delete_team(user_id, &req.name).await
+            }
+            GroupOperationType::GROUP_OPERATION_MODIFY => {
+                self.team_service.rename_team(user_id, &req.name, &req.new_name).await
+            }
+            GroupOperationType::GROUP_OPERATION_SORT => {
+                self.team_service.reorder_teams(user_id, req.names).await
+            }
+            GroupOperationType::GROUP_OPERATION_UNKNOWN => {
+                Err(Error::ParamInvalid("不支持的分组操作".to_string()))
+            }
+        };
+
+        let mut reply = friend::UpdateTeamInfoReply::new();
+        reply.type_ = operation.into();
+        match res {
+            Ok(list) => {
+                reply.code = ErrorCode::ERR_OK.into();
+                reply.teams = list.teams;
+            }
+            Err(err) => {
+                reply.code = error_code(&err).into();
+                reply.msg = err.to_string();
+            }
+        }
+        Packet::from_message(MsgType::UPDATE_TEAM_INFO, &reply)
+            .map(Some)
+            .map_err(|err| {
+                tracing::error!("encode update team info reply failed, {err:#}");
+                Error::InternalServerError
+            })
+    }
+}
+
+impl From<&FriendTeam> for friend::FriendTeam {
+    fn from(value: &FriendTeam) -> Self {
+        let mut team = friend::FriendTeam::new();
+        team.name = value.name.clone();
+        team.friends = value.friends.iter().map(Into::into).collect();
+        team
+    }
+}
+
+impl From<&FriendInfo> for friend::FriendInfo {
+    fn from(value: &FriendInfo) -> Self {
+        let mut info = friend::FriendInfo::new();
+        info.profile = MessageField::some((&value.profile).into());
+        info.mark_name = value.mark_name.clone().unwrap_or_default();
+        info.status = OnlineStatus::from(value.status).into();
+        info
+    }
+}
+
+impl From<&FriendApplyInfo> for friend::FriendApply {
+    fn from(value: &FriendApplyInfo) -> Self {
+        let mut apply = friend::FriendApply::new();
+        apply.apply_id = value.apply_id;
+        apply.from = MessageField::some((&value.from).into());
+        apply.to = MessageField::some((&value.to).into());
+        apply.message = value.message.clone().unwrap_or_default();
+        apply.status = FriendOperationApplyType::from(value.status).into();
+        apply.create_time = value.create_time.timestamp_millis();
+        apply
+    }
+}
+
+impl From<&PublicProfile> for net::UserProfile {
+    fn from(value: &PublicProfile) -> Self {
+        let mut profile = net::UserProfile::new();
+        profile.user_id = value.user_id.clone();
+        profile.user_name = value.user_name.clone();
+        profile.nick_name = value.nick_name.clone();
+        profile.gender = net::Gender::from(&value.gender).into();
+        profile.signature = value.signature.clone().unwrap_or_default();
+        profile.facetype = value.face_type.unwrap_or_default();
+        profile.customface = value.custom_face.clone().unwrap_or_default();
+        profile.customfacefmt = value.custom_face_fmt.clone().unwrap_or_default();
+        profile
+    }
+}
+
+/// 公开资料, 不包含手机号及邮箱
+impl From<&UserInfo> for net::UserProfile {
+    fn from(value: &UserInfo) -> Self {
+        let (base, detail) = (&value.base_info, &value.detail_info);
+        let mut profile = net::UserProfile::new();
+        profile.user_id = base.user_id.clone();
+        profile.user_name = base.user_name.clone();
+        profile.nick_name = base.nick_name.clone();
+        profile.gender = net::Gender::from(&base.gender).into();
+        profile.birthday = detail.birthday.unwrap_or_default();
+        profile.signature = detail.signature.clone().unwrap_or_default();
+        profile.address = detail.address.clone().unwrap_or_default();
+        profile.facetype = detail.face_type.unwrap_or_default();
+        profile.customface = detail.custom_face.clone().unwrap_or_default();
+        profile.customfacefmt = detail.custom_face_fmt.clone().unwrap_or_default();
+        profile
+    }
+}
+
+impl From<net::Gender> for user::Gender {
+    fn from(value: net::Gender) -> Self {
+        match value {
+            net::Gender::Male => user::Gender::Male,
+            net::Gender::Female => user::Gender::Female,
+            net::Gender::Unknown => user::Gender::Unknown,
+        }
+    }
+}
+
+impl From<&user::Gender> for net::Gender {
+    fn from(value: &user::Gender) -> Self {
+        match value {
+            user::Gender::Male => net::Gender::Male,
+            user::Gender::Female => net::Gender::Female,
+            user::Gender::Unknown => net::Gender::Unknown,
+        }
+    }
+}
+
+impl From<ClientType> for user::ClientType {
+    fn from(value: ClientType) -> Self {
+        match value {
+            ClientType::WINDOWS => user::ClientType::WINDOWS,
+            ClientType::LINUX => user::ClientType::LINUX,
+            ClientType::MAC => user::ClientType::MAC,
+            ClientType::ANDROID => user::ClientType::ANDROID,
+            ClientType::IOS => user::ClientType::IOS,
+            ClientType::IPAD => user::ClientType::IPAD,
+        }
+    }
+}
+
+impl From<user::ClientType> for ClientType {
+    fn from(value: user::ClientType) -> Self {
+        match value {
+            user::ClientType::WINDOWS => ClientType::WINDOWS,
+            user::ClientType::LINUX => ClientType::LINUX,
+            user::ClientType::MAC => ClientType::MAC,
+            user::ClientType::ANDROID => ClientType::ANDROID,
+            user::ClientType::IOS => ClientType::IOS,
+            user::ClientType::IPAD => ClientType::IPAD,
+        }
+    }
+}
+
+impl From<OnlineStatus> for user::OnlineStatus {
+    fn from(value: OnlineStatus) -> Self {
+        match value {
+            OnlineStatus::OFFLINE => user::OnlineStatus::OFFLINE,
+            OnlineStatus::INVISIBLE => user::OnlineStatus::INVISIBLE,
+            OnlineStatus::WIFI => user::OnlineStatus::WIFI,
+            OnlineStatus::CELLULAR_3G => user::OnlineStatus::AndroidCellular,
+            OnlineStatus::CELLULAR_4G => user::OnlineStatus::IOSCellular,
+            OnlineStatus::CELLULAR_5G => user::OnlineStatus::MacCellular,
+        }
+    }
+}
+
+impl From<user::OnlineStatus> for OnlineStatus {
+    fn from(value: user::OnlineStatus) -> Self {
+        match value {
+            user::OnlineStatus::OFFLINE => OnlineStatus::OFFLINE,
+            user::OnlineStatus::INVISIBLE => OnlineStatus::INVISIBLE,
+            user::OnlineStatus::WIFI => OnlineStatus::WIFI,
+            user::OnlineStatus::AndroidCellular => OnlineStatus::CELLULAR_3G,
+            user::OnlineStatus::IOSCellular => OnlineStatus::CELLULAR_4G,
+            user::OnlineStatus::MacCellular => OnlineStatus::CELLULAR_5G,
+        }
+    }
+}
@@ -1 +1,11 @@
+pub mod codec;
+pub mod delivery;
+pub mod dispatcher;
+pub mod error;
+pub mod gateway;
+pub mod handler;
+pub mod packet;
+pub mod registry;
+pub mod session;
 pub mod stubs;
+pub mod ws;
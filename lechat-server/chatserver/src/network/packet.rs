@@ -0,0 +1,40 @@
+use bytes::Bytes;
+use protobuf::Message;
+
+use crate::network::error::Result;
+use crate::network::stubs::chatmsg::MsgType;
+
+/// 长连接上收发的数据包, 线上格式见`codec::PacketCodec`
+#[derive(Debug, Clone)]
+pub struct Packet {
+    pub msg_type: MsgType,
+    /// 协议头中的序列号, 应答与请求一致, 推送由会话分配
+    pub seq: u32,
+    pub body: Bytes,
+}
+
+impl Packet {
+    pub fn new(msg_type: MsgType, body: Bytes) -> Self {
+        Packet {
+            msg_type,
+            seq: 0,
+            body,
+        }
+    }
+
+    pub fn with_seq(mut self, seq: u32) -> Self {
+        self.seq = seq;
+        self
+    }
+
+    /// 序列化protobuf消息生成数据包
+    pub fn from_message<M: Message>(msg_type: MsgType, msg: &M) -> Result<Self> {
+        let body = msg.write_to_bytes()?;
+        Ok(Packet::new(msg_type, Bytes::from(body)))
+    }
+
+    /// 将包体反序列化为protobuf消息
+    pub fn parse<M: Message>(&self) -> Result<M> {
+        Ok(M::parse_from_bytes(&self.body)?)
+    }
+}
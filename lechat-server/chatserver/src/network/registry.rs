@@ -0,0 +1,408 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use fred::prelude::{Expiration, KeysInterface, LuaInterface, PubsubInterface, RedisResult};
+use futures::future::join_all;
+use protobuf::{Enum, EnumFull};
+use shaku::{Component, Interface};
+
+use crate::base::response::{Error, Result};
+use crate::components::redis::{self, IRedisService};
+use crate::network::packet::Packet;
+use crate::network::session::{ISessionManager, Session, SessionUser};
+use crate::network::stubs::chatmsg::{ClientType, KickUser, MsgType, OnlineStatus};
+
+const SESSION_KEY_PREFIX: &str = "session:user:";
+/// 各设备类型登录时声明的在线状态, 与会话登记同步增删
+const STATUS_KEY_PREFIX: &str = "session:status:";
+const KICK_CHANNEL_PREFIX: &str = "session:kick:";
+/// 实例存活标记, 由实例定期续期
+const INSTANCE_KEY_PREFIX: &str = "session:instance:";
+/// 会话登记保留时长(秒), 长期没有登录的用户的键由此回收
+const SESSION_TTL: i64 = 7 * 24 * 3600;
+/// 实例存活标记的有效期, 超时未续期的实例视为已退出, 其上的会话登记在下次查询时清理
+const INSTANCE_TTL: Duration = Duration::from_secs(30);
+const INSTANCE_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 登记新会话并返回同一设备类型上的旧会话
+const REGISTER_SCRIPT: &str = r#"
+local old = redis.call('HGET', KEYS[1], ARGV[1])
+redis.call('HSET', KEYS[1], ARGV[1], ARGV[2])
+redis.call('EXPIRE', KEYS[1], ARGV[3])
+redis.call('HSET', KEYS[2], ARGV[1], ARGV[4])
+redis.call('EXPIRE', KEYS[2], ARGV[3])
+return old
+"#;
+
+/// 返回用户的会话登记及在线状态, 每个会话依次为设备类型、会话、在线状态
+const SESSIONS_SCRIPT: &str = r#"
+local entries = redis.call('HGETALL', KEYS[1])
+local res = {}
+for i = 1, #entries, 2 do
+    table.insert(res, entries[i])
+    table.insert(res, entries[i + 1])
+    table.insert(res, redis.call('HGET', KEYS[2], entries[i]) or '')
+end
+return res
+"#;
+
+/// 仅当登记的仍是该会话时注销, 避免删除同设备类型上的新会话
+const UNREGISTER_SCRIPT: &str = r#"
+if redis.call('HGET', KEYS[1], ARGV[1]) == ARGV[2] then
+    redis.call('HDEL', KEYS[2], ARGV[1])
+    return redis.call('HDEL', KEYS[1], ARGV[1])
+end
+return 0
+"#;
+
+/// 多实例共享的用户会话登记, 每个用户在每种设备类型上最多一个会话
+#[async_trait]
+pub trait ISessionRegistry: Interface {
+    /// 登录成功后登记会话, 同一设备类型上的旧会话会被踢下线
+    async fn register(&self, session: &Arc<Session>, user: &SessionUser) -> Result<()>;
+    /// 会话关闭后注销, 返回是否注销了该会话的登记. 已被踢下线或退出登录时登记已提前注销
+    async fn unregister(&self, session: &Arc<Session>) -> bool;
+    /// 用户在线的设备类型及会话所在的实例
+    async fn locate(&self, user_id: &str) -> Result<Vec<(ClientType, String)>>;
+    /// 用户在线的设备类型
+    async fn online_clients(&self, user_id: &str) -> Result<Vec<ClientType>>;
+    /// 用户各在线设备登录时声明的在线状态
+    async fn online_status(&self, user_id: &str) -> Result<Vec<(ClientType, OnlineStatus)>>;
+    /// 踢掉用户指定设备类型上的会话, 不指定时踢掉全部设备, 返回被踢的设备类型
+    async fn kick(
+        &self,
+        user_id: &str,
+        client_type: Option<ClientType>,
+        reason: &str,
+    ) -> Result<Vec<ClientType>>;
+    /// 踢掉本实例上的会话
+    fn kick_local(&self, session_id: u64, reason: &str);
+    /// 本实例的标识
+    fn instance_id(&self) -> &str;
+}
+
+/// 存活实例上的一条会话登记
+struct Registration {
+    field: String,
+    value: String,
+    status: Option<OnlineStatus>,
+}
+
+#[derive(Component)]
+#[shaku(interface = ISessionRegistry)]
+pub struct SessionRegistryImpl {
+    /// 实例标识, 每次启动生成
+    #[shaku(no_default)]
+    instance_id: String,
+    #[shaku(inject)]
+    session_manager: Arc<dyn ISessionManager>,
+    #[shaku(inject)]
+    redis_cli: Arc<dyn IRedisService>,
+}
+
+impl SessionRegistryImpl {
+    fn session_value(&self, session_id: u64) -> String {
+        format!("{}:{}", self.instance_id, session_id)
+    }
+
+    /// 存活实例上的会话登记, 已退出实例遗留的登记顺带注销
+    async fn sessions(&self, user_id: &str) -> Result<Vec<Registration>> {
+        let entries: Vec<String> = self
+            .redis_cli
+            .get_conn()
+            .eval(SESSIONS_SCRIPT, session_keys(user_id), Vec::<String>::new())
+            .await
+            .map_err(|err| {
+                tracing::error!("load sessions of user {} failed, {err:#}", user_id);
+                Error::InternalServerError
+            })?;
+        let registrations: Vec<Registration> = entries
+            .chunks_exact(3)
+            .map(|v| Registration {
+                field: v[0].clone(),
+                value: v[1].clone(),
+                status: v[2].parse().ok().and_then(OnlineStatus::from_i32),
+            })
+            .collect();
+
+        // 存活标记与用户的键不在同一个槽, 在脚本外逐个实例查询
+        let instances: HashSet<&str> = registrations
+            .iter()
+            .filter_map(|r| parse_session_value(&r.value))
+            .map(|(instance_id, _)| instance_id)
+            .filter(|instance_id| *instance_id != self.instance_id)
+            .collect();
+        let dead: HashSet<String> = join_all(
+            instances
+                .into_iter()
+                .map(|instance_id| async move { (instance_id, self.instance_alive(instance_id).await) }),
+        )
+        .await
+        .into_iter()
+        .filter(|(_, alive)| !alive)
+        .map(|(instance_id, _)| instance_id.to_string())
+        .collect();
+
+        let mut live = Vec::with_capacity(registrations.len());
+        for r in registrations {
+            let alive =
+                parse_session_value(&r.value).is_some_and(|(instance_id, _)| !dead.contains(instance_id));
+            if alive {
+                live.push(r);
+                continue;
+            }
+            tracing::info!("remove stale session {} of user {}", r.value, user_id);
+            let res: RedisResult<i64> = self
+                .redis_cli
+                .get_conn()
+                .eval(UNREGISTER_SCRIPT, session_keys(user_id), vec![r.field, r.value])
+                .await;
+            if let Err(err) = res {
+                tracing::error!("remove stale session of user {} failed, {err:#}", user_id);
+            }
+        }
+        Ok(live)
+    }
+
+    /// 实例是否仍在续期存活标记, 查询失败时按存活处理, 避免误删登记
+    async fn instance_alive(&self, instance_id: &str) -> bool {
+        let res: RedisResult<bool> = self.redis_cli.get_conn().exists(instance_key(instance_id)).await;
+        res.unwrap_or_else(|err| {
+            tracing::error!("check liveness of instance {} failed, {err:#}", instance_id);
+            true
+        })
+    }
+
+    /// 通知会话所在的实例踢掉会话
+    async fn kick_session(&self, instance_id: &str, session_id: u64, reason: &str) -> Result<()> {
+        if instance_id == self.instance_id {
+            self.kick_local(session_id, reason);
+            return Ok(());
+        }
+        tracing::info!("kick session {} on instance {}", session_id, instance_id);
+        self.redis_cli
+            .get_conn()
+            .publish::<(), _, _>(
+                format!("{KICK_CHANNEL_PREFIX}{instance_id}"),
+                format!("{session_id}:{reason}"),
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("publish kick of session {} failed, {err:#}", session_id);
+                Error::InternalServerError
+            })
+    }
+}
+
+#[async_trait]
+impl ISessionRegistry for SessionRegistryImpl {
+    async fn register(&self, session: &Arc<Session>, user: &SessionUser) -> Result<()> {
+        let keys = session_keys(&user.user_id);
+        let field = client_field(user.client_type);
+        let redis_cli = self.redis_cli.get_conn();
+        let old: Option<String> = redis_cli
+            .eval(
+                REGISTER_SCRIPT,
+                keys,
+                vec![
+                    field,
+                    self.session_value(session.id()),
+                    SESSION_TTL.to_string(),
+                    user.status.value().to_string(),
+                ],
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("register session of user {} failed, {err:#}", user.user_id);
+                Error::InternalServerError
+            })?;
+
+        let Some((instance_id, session_id)) = old.as_deref().and_then(parse_session_value) else {
+            return Ok(());
+        };
+        if instance_id == self.instance_id && session_id == session.id() {
+            return Ok(());
+        }
+        self.kick_session(instance_id, session_id, "账号在其他设备登录").await
+    }
+
+    async fn unregister(&self, session: &Arc<Session>) -> bool {
+        let Some(user) = session.user() else {
+            return false;
+        };
+        let keys = session_keys(&user.user_id);
+        let args = vec![client_field(user.client_type), self.session_value(session.id())];
+        let res: RedisResult<i64> = self.redis_cli.get_conn().eval(UNREGISTER_SCRIPT, keys, args).await;
+        match res {
+            Ok(removed) => removed > 0,
+            Err(err) => {
+                tracing::error!(
+                    "unregister session {} of user {} failed, {err:#}",
+                    session.id(),
+                    user.user_id
+                );
+                false
+            }
+        }
+    }
+
+    async fn locate(&self, user_id: &str) -> Result<Vec<(ClientType, String)>> {
+        Ok(self
+            .sessions(user_id)
+            .await?
+            .into_iter()
+            .filter_map(|r| {
+                let client_type = parse_client_field(&r.field)?;
+                let (instance_id, _) = parse_session_value(&r.value)?;
+                Some((client_type, instance_id.to_string()))
+            })
+            .collect())
+    }
+
+    async fn online_clients(&self, user_id: &str) -> Result<Vec<ClientType>> {
+        Ok(self
+            .sessions(user_id)
+            .await?
+            .iter()
+            .filter_map(|r| parse_client_field(&r.field))
+            .collect())
+    }
+
+    async fn online_status(&self, user_id: &str) -> Result<Vec<(ClientType, OnlineStatus)>> {
+        Ok(self
+            .sessions(user_id)
+            .await?
+            .into_iter()
+            .filter_map(|r| Some((parse_client_field(&r.field)?, r.status?)))
+            .collect())
+    }
+
+    async fn kick(
+        &self,
+        user_id: &str,
+        client_type: Option<ClientType>,
+        reason: &str,
+    ) -> Result<Vec<ClientType>> {
+        let keys = session_keys(user_id);
+        let mut kicked = Vec::new();
+        for Registration { field, value, .. } in self.sessions(user_id).await? {
+            let Some(client) = parse_client_field(&field) else {
+                continue;
+            };
+            if client_type.is_some_and(|v| v != client) {
+                continue;
+            }
+            // 先注销再踢人, 会话关闭前即视为离线
+            let res: RedisResult<i64> = self
+                .redis_cli
+                .get_conn()
+                .eval(UNREGISTER_SCRIPT, keys.clone(), vec![field, value.clone()])
+                .await;
+            if let Err(err) = res {
+                tracing::error!("unregister session of user {} failed, {err:#}", user_id);
+                return Err(Error::InternalServerError);
+            }
+            if let Some((instance_id, session_id)) = parse_session_value(&value) {
+                self.kick_session(instance_id, session_id, reason).await?;
+            }
+            kicked.push(client);
+        }
+        Ok(kicked)
+    }
+
+    fn kick_local(&self, session_id: u64, reason: &str) {
+        let Some(session) = self.session_manager.get(session_id) else {
+            return;
+        };
+        let Some(user) = session.user() else {
+            return;
+        };
+        tracing::info!("kick session {} of user {}, {}", session_id, user.user_id, reason);
+        let mut kick = KickUser::new();
+        kick.client_type = user.client_type.into();
+        kick.reason = reason.to_string();
+        match Packet::from_message(MsgType::KICK_USER, &kick) {
+            Ok(packet) => {
+                let _ = session.send(packet);
+            }
+            Err(err) => tracing::error!("encode kick message failed, {err:#}"),
+        }
+        session.close();
+    }
+
+    fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+}
+
+/// 会话登记及在线状态的键, user_id作为哈希标签, 保证脚本操作的两个键在Redis Cluster的同一个槽
+fn session_keys(user_id: &str) -> Vec<String> {
+    vec![
+        format!("{SESSION_KEY_PREFIX}{{{user_id}}}"),
+        format!("{STATUS_KEY_PREFIX}{{{user_id}}}"),
+    ]
+}
+
+fn instance_key(instance_id: &str) -> String {
+    format!("{INSTANCE_KEY_PREFIX}{instance_id}")
+}
+
+fn client_field(client_type: ClientType) -> String {
+    client_type.descriptor().name().to_lowercase()
+}
+
+fn parse_client_field(field: &str) -> Option<ClientType> {
+    ClientType::enum_descriptor()
+        .value_by_name(&field.to_uppercase())
+        .and_then(|v| v.cast::<ClientType>())
+}
+
+/// 会话登记的值, 格式为`instance_id:session_id`
+fn parse_session_value(value: &str) -> Option<(&str, u64)> {
+    let (instance_id, session_id) = value.rsplit_once(':')?;
+    Some((instance_id, session_id.parse().ok()?))
+}
+
+/// 订阅本实例的踢人通知, 其他实例登录同一设备类型或退出登录时由此关闭本实例上的会话
+pub async fn listen_kick(registry: Arc<dyn ISessionRegistry>, redis_cli: Arc<dyn IRedisService>) {
+    let channel = format!("{KICK_CHANNEL_PREFIX}{}", registry.instance_id());
+    redis::subscribe(redis_cli.get_conn(), channel, |value| {
+        let kick = value.as_string().and_then(|v| {
+            let (session_id, reason) = v.split_once(':')?;
+            Some((session_id.parse::<u64>().ok()?, reason.to_string()))
+        });
+        match kick {
+            Some((session_id, reason)) => registry.kick_local(session_id, &reason),
+            None => tracing::warn!("invalid kick notification {:?}", value),
+        }
+        async {}
+    })
+    .await
+}
+
+/// 定期续期本实例的存活标记, 实例异常退出后其上的会话登记随标记过期失效
+pub async fn keep_instance_alive(registry: Arc<dyn ISessionRegistry>, redis_cli: Arc<dyn IRedisService>) {
+    let key = instance_key(registry.instance_id());
+    let mut ticker = tokio::time::interval(INSTANCE_KEEPALIVE_INTERVAL);
+    loop {
+        ticker.tick().await;
+        let res: RedisResult<()> = redis_cli
+            .get_conn()
+            .set(
+                &key,
+                1,
+                Some(Expiration::EX(INSTANCE_TTL.as_secs() as i64)),
+                None,
+                false,
+            )
+            .await;
+        if let Err(err) = res {
+            tracing::error!(
+                "refresh liveness of instance {} failed, {err:#}",
+                registry.instance_id()
+            );
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use library::utils;
+use shaku::{Component, HasComponent, Interface};
+use tokio::sync::mpsc;
+
+use crate::base::config::Config;
+use crate::components::Modules;
+use crate::network::delivery::IDeliveryService;
+use crate::network::error::{Error, Result};
+use crate::network::packet::Packet;
+use crate::network::registry::ISessionRegistry;
+use crate::network::stubs::chatmsg::{ClientType, OnlineStatus};
+use crate::service::presence::IPresenceService;
+
+/// 发往连接写端的指令
+#[derive(Debug)]
+pub enum Outbound {
+    Packet(Packet),
+    Close,
+}
+
+/// 会话登录后绑定的用户
+#[derive(Debug, Clone)]
+pub struct SessionUser {
+    pub user_id: String,
+    pub client_type: ClientType,
+    /// 登录时声明的在线状态
+    pub status: OnlineStatus,
+}
+
+/// 握手时客户端声明的信息
+#[derive(Debug, Clone)]
+pub struct ClientInfo {
+    pub version: u32,
+    pub client_type: ClientType,
+}
+
+/// 默认检查空闲连接的间隔
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+/// 默认连接空闲超时时间
+const DEFAULT_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// 等待客户端确认的推送
+struct PendingPush {
+    packet: Packet,
+    attempts: u32,
+    sent_at: Instant,
+}
+
+/// 长连接会话, 与具体传输方式无关
+pub struct Session {
+    id: u64,
+    peer: SocketAddr,
+    client: RwLock<Option<ClientInfo>>,
+    user: RwLock<Option<SessionUser>>,
+    /// 最后一次收到数据的时间戳(毫秒)
+    last_active: AtomicU64,
+    /// 服务端推送的序列号
+    next_seq: AtomicU32,
+    pending: Mutex<BTreeMap<u32, PendingPush>>,
+    tx: mpsc::UnboundedSender<Outbound>,
+}
+
+impl Session {
+    fn new(id: u64, peer: SocketAddr, tx: mpsc::UnboundedSender<Outbound>) -> Self {
+        Session {
+            id,
+            peer,
+            client: RwLock::new(None),
+            user: RwLock::new(None),
+            last_active: AtomicU64::new(utils::time::now_timestamp_millis() as u64),
+            next_seq: AtomicU32::new(0),
+            pending: Mutex::new(BTreeMap::new()),
+            tx,
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn peer(&self) -> SocketAddr {
+        self.peer
+    }
+
+    /// 握手成功后的客户端信息, 未握手返回None
+    pub fn client(&self) -> Option<ClientInfo> {
+        self.client.read().unwrap().clone()
+    }
+
+    /// 握手成功后记录客户端信息
+    pub fn set_client(&self, client: ClientInfo) {
+        *self.client.write().unwrap() = Some(client);
+    }
+
+    /// 当前登录的用户, 未登录返回None
+    pub fn user(&self) -> Option<SessionUser> {
+        self.user.read().unwrap().clone()
+    }
+
+    /// 收到客户端数据后刷新活跃时间
+    pub fn touch(&self) {
+        self.last_active
+            .store(utils::time::now_timestamp_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// 距最后一次收到数据的时长
+    pub fn idle_time(&self) -> Duration {
+        let now = utils::time::now_timestamp_millis() as u64;
+        Duration::from_millis(now.saturating_sub(self.last_active.load(Ordering::Relaxed)))
+    }
+
+    /// 向客户端推送数据包, 返回分配的序列号
+    pub fn send(&self, packet: Packet) -> Result<u32> {
+        let seq = self.next_seq();
+        self.write(packet.with_seq(seq))?;
+        Ok(seq)
+    }
+
+    /// 推送需要客户端确认的数据包, 未确认前由`retransmit`按退避间隔重发
+    pub fn send_reliable(&self, packet: Packet) -> Result<u32> {
+        let seq = self.next_seq();
+        let packet = packet.with_seq(seq);
+        self.pending.lock().unwrap().insert(
+            seq,
+            PendingPush {
+                packet: packet.clone(),
+                attempts: 1,
+                sent_at: Instant::now(),
+            },
+        );
+        if let Err(err) = self.write(packet) {
+            self.pending.lock().unwrap().remove(&seq);
+            return Err(err);
+        }
+        Ok(seq)
+    }
+
+    /// 客户端确认收到推送, 返回该序列号是否在等待确认
+    pub fn ack(&self, seq: u32) -> bool {
+        self.pending.lock().unwrap().remove(&seq).is_some()
+    }
+
+    /// 重发到期未确认的推送, 第n次重发间隔为`interval * 2^(n-1)`,
+    /// 达到`max_attempts`次仍未确认的推送移出等待队列并返回
+    pub fn retransmit(&self, interval: Duration, max_attempts: u32) -> Vec<Packet> {
+        let now = Instant::now();
+        let mut expired = Vec::new();
+        self.pending.lock().unwrap().retain(|seq, push| {
+            let backoff = interval.saturating_mul(1 << (push.attempts - 1).min(16));
+            if now.duration_since(push.sent_at) < backoff {
+                return true;
+            }
+            if push.attempts >= max_attempts || self.write(push.packet.clone()).is_err() {
+                tracing::info!("push {} of session {} is not acknowledged", seq, self.id);
+                expired.push(push.packet.clone());
+                return false;
+            }
+            push.attempts += 1;
+            push.sent_at = now;
+            true
+        });
+        expired
+    }
+
+    /// 取出全部未确认的推送
+    pub fn take_pending(&self) -> Vec<Packet> {
+        let pending = std::mem::take(&mut *self.pending.lock().unwrap());
+        pending.into_values().map(|push| push.packet).collect()
+    }
+
+    /// 通知写端关闭连接
+    pub fn close(&self) {
+        let _ = self.tx.send(Outbound::Close);
+    }
+
+    fn next_seq(&self) -> u32 {
+        self.next_seq.fetch_add(1, Ordering::Relaxed).wrapping_add(1)
+    }
+
+    fn write(&self, packet: Packet) -> Result<()> {
+        self.tx
+            .send(Outbound::Packet(packet))
+            .map_err(|_| Error::SessionClosed(self.id))
+    }
+}
+
+pub trait ISessionManager: Interface {
+    /// 为新连接创建会话, 返回会话及其写端接收的指令
+    fn create(&self, peer: SocketAddr) -> (Arc<Session>, mpsc::UnboundedReceiver<Outbound>);
+    /// 会话登录成功后绑定用户
+    fn bind(&self, session: &Arc<Session>, user: SessionUser);
+    /// 连接断开后移除会话, 用户没有其他会话时标记为离线
+    fn remove(&self, session_id: u64);
+    /// 按id查找会话
+    fn get(&self, session_id: u64) -> Option<Arc<Session>>;
+    /// 查找用户的全部会话
+    fn find_by_user(&self, user_id: &str) -> Vec<Arc<Session>>;
+    /// 用户是否有在线会话
+    fn is_online(&self, user_id: &str) -> bool;
+    /// 全部会话
+    fn sessions(&self) -> Vec<Arc<Session>>;
+    /// 空闲时间超过`timeout`的会话
+    fn idle_sessions(&self, timeout: Duration) -> Vec<Arc<Session>>;
+    /// 向用户的全部会话推送数据包, 返回推送成功的会话数
+    fn push(&self, user_id: &str, packet: Packet) -> usize;
+}
+
+#[derive(Component)]
+#[shaku(interface = ISessionManager)]
+pub struct SessionManagerImpl {
+    next_id: AtomicU64,
+    sessions: RwLock<HashMap<u64, Arc<Session>>>,
+}
+
+impl ISessionManager for SessionManagerImpl {
+    fn create(&self, peer: SocketAddr) -> (Arc<Session>, mpsc::UnboundedReceiver<Outbound>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let session = Arc::new(Session::new(id, peer, tx));
+        self.sessions.write().unwrap().insert(id, session.clone());
+        tracing::debug!("session {} created for {}", id, peer);
+        (session, rx)
+    }
+
+    fn bind(&self, session: &Arc<Session>, user: SessionUser) {
+        tracing::info!("session {} bound to user {}", session.id(), user.user_id);
+        *session.user.write().unwrap() = Some(user);
+    }
+
+    fn remove(&self, session_id: u64) {
+        let mut sessions = self.sessions.write().unwrap();
+        let Some(session) = sessions.remove(&session_id) else {
+            return;
+        };
+        tracing::debug!("session {} removed", session_id);
+        if let Some(user) = session.user() {
+            if !sessions.values().any(|s| s.user().is_some_and(|u| u.user_id == user.user_id)) {
+                tracing::info!("user {} is offline", user.user_id);
+            }
+        }
+    }
+
+    fn get(&self, session_id: u64) -> Option<Arc<Session>> {
+        self.sessions.read().unwrap().get(&session_id).cloned()
+    }
+
+    fn find_by_user(&self, user_id: &str) -> Vec<Arc<Session>> {
+        self.sessions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|s| s.user().is_some_and(|u| u.user_id == user_id))
+            .cloned()
+            .collect()
+    }
+
+    fn is_online(&self, user_id: &str) -> bool {
+        !self.find_by_user(user_id).is_empty()
+    }
+
+    fn sessions(&self) -> Vec<Arc<Session>> {
+        self.sessions.read().unwrap().values().cloned().collect()
+    }
+
+    fn idle_sessions(&self, timeout: Duration) -> Vec<Arc<Session>> {
+        self.sessions
+            .read()
+            .unwrap()
+            .values()
+            .filter(|s| s.idle_time() > timeout)
+            .cloned()
+            .collect()
+    }
+
+    fn push(&self, user_id: &str, packet: Packet) -> usize {
+        self.find_by_user(user_id)
+            .iter()
+            .filter(|s| s.send(packet.clone()).is_ok())
+            .count()
+    }
+}
+
+/// 连接关闭后注销会话并通知好友离线, TCP与WebSocket连接共用
+pub async fn close_session(modules: &Modules, session: &Arc<Session>) {
+    let session_manager: &dyn ISessionManager = modules.resolve_ref();
+    session_manager.remove(session.id());
+    let session_registry: &dyn ISessionRegistry = modules.resolve_ref();
+    let unregistered = session_registry.unregister(session).await;
+    let delivery: &dyn IDeliveryService = modules.resolve_ref();
+    delivery.session_closed(session).await;
+
+    // 被踢下线或退出登录的会话已由发起方通知, 同设备类型上的新会话仍然在线
+    let Some(user) = session.user().filter(|_| unregistered) else {
+        return;
+    };
+    let presence: &dyn IPresenceService = modules.resolve_ref();
+    if let Err(err) = presence
+        .notify_status(&user.user_id, user.client_type, OnlineStatus::OFFLINE)
+        .await
+    {
+        tracing::error!("notify offline of user {} failed, {err:#}", user.user_id);
+    }
+}
+
+/// 定期关闭空闲超时的连接, 客户端断网后遗留的半开连接由此回收
+pub async fn reap_idle_sessions(session_manager: Arc<dyn ISessionManager>, cfg: Arc<Config>) {
+    let timeout = cfg.heartbeat.timeout.unwrap_or(DEFAULT_HEARTBEAT_TIMEOUT);
+    let mut ticker = tokio::time::interval(cfg.heartbeat.interval.unwrap_or(DEFAULT_HEARTBEAT_INTERVAL));
+    loop {
+        ticker.tick().await;
+        for session in session_manager.idle_sessions(timeout) {
+            tracing::info!(
+                "session {} from {} is idle for {:?}, close it",
+                session.id(),
+                session.peer(),
+                session.idle_time()
+            );
+            session.close();
+            session_manager.remove(session.id());
+        }
+    }
+}
@@ -0,0 +1,419 @@
+// This file is generated by rust-protobuf 3.5.1. Do not edit
+// .proto file is parsed by protoc 25.3
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `chat.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_5_1;
+
+// @@protoc_insertion_point(message:microchat.chat.ChatMessage)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ChatMessage {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.chat.ChatMessage.msg_id)
+    pub msg_id: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.chat.ChatMessage.sender_id)
+    pub sender_id: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.chat.ChatMessage.target_id)
+    pub target_id: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.chat.ChatMessage.content)
+    pub content: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:microchat.chat.ChatMessage.create_time)
+    pub create_time: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.chat.ChatMessage.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChatMessage {
+    fn default() -> &'a ChatMessage {
+        <ChatMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChatMessage {
+    pub fn new() -> ChatMessage {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg_id",
+            |m: &ChatMessage| { &m.msg_id },
+            |m: &mut ChatMessage| { &mut m.msg_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sender_id",
+            |m: &ChatMessage| { &m.sender_id },
+            |m: &mut ChatMessage| { &mut m.sender_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "target_id",
+            |m: &ChatMessage| { &m.target_id },
+            |m: &mut ChatMessage| { &mut m.target_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "content",
+            |m: &ChatMessage| { &m.content },
+            |m: &mut ChatMessage| { &mut m.content },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "create_time",
+            |m: &ChatMessage| { &m.create_time },
+            |m: &mut ChatMessage| { &mut m.create_time },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChatMessage>(
+            "ChatMessage",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ChatMessage {
+    const NAME: &'static str = "ChatMessage";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.msg_id = is.read_string()?;
+                },
+                18 => {
+                    self.sender_id = is.read_string()?;
+                },
+                26 => {
+                    self.target_id = is.read_string()?;
+                },
+                34 => {
+                    self.content = is.read_bytes()?;
+                },
+                40 => {
+                    self.create_time = is.read_int64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.msg_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.msg_id);
+        }
+        if !self.sender_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.sender_id);
+        }
+        if !self.target_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.target_id);
+        }
+        if !self.content.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.content);
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::int64_size(5, self.create_time);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.msg_id.is_empty() {
+            os.write_string(1, &self.msg_id)?;
+        }
+        if !self.sender_id.is_empty() {
+            os.write_string(2, &self.sender_id)?;
+        }
+        if !self.target_id.is_empty() {
+            os.write_string(3, &self.target_id)?;
+        }
+        if !self.content.is_empty() {
+            os.write_bytes(4, &self.content)?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(5, self.create_time)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChatMessage {
+        ChatMessage::new()
+    }
+
+    fn clear(&mut self) {
+        self.msg_id.clear();
+        self.sender_id.clear();
+        self.target_id.clear();
+        self.content.clear();
+        self.create_time = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChatMessage {
+        static instance: ChatMessage = ChatMessage {
+            msg_id: ::std::string::String::new(),
+            sender_id: ::std::string::String::new(),
+            target_id: ::std::string::String::new(),
+            content: ::std::vec::Vec::new(),
+            create_time: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ChatMessage {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChatMessage").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChatMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChatMessage {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.chat.ChatReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ChatReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.chat.ChatReply.code)
+    pub code: ::protobuf::EnumOrUnknown<super::chatmsg::ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.chat.ChatReply.msg)
+    pub msg: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.chat.ChatReply.msg_id)
+    pub msg_id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.chat.ChatReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ChatReply {
+    fn default() -> &'a ChatReply {
+        <ChatReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChatReply {
+    pub fn new() -> ChatReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &ChatReply| { &m.code },
+            |m: &mut ChatReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &ChatReply| { &m.msg },
+            |m: &mut ChatReply| { &mut m.msg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg_id",
+            |m: &ChatReply| { &m.msg_id },
+            |m: &mut ChatReply| { &mut m.msg_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChatReply>(
+            "ChatReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ChatReply {
+    const NAME: &'static str = "ChatReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                26 => {
+                    self.msg_id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if !self.msg_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.msg_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if !self.msg_id.is_empty() {
+            os.write_string(3, &self.msg_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ChatReply {
+        ChatReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.msg_id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ChatReply {
+        static instance: ChatReply = ChatReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            msg_id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ChatReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ChatReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ChatReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChatReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\nchat.proto\x12\x0emicrochat.chat\x1a\rchatmsg.proto\"\x99\x01\n\x0bC\
+    hatMessage\x12\x15\n\x06msg_id\x18\x01\x20\x01(\tR\x05msgId\x12\x1b\n\ts\
+    ender_id\x18\x02\x20\x01(\tR\x08senderId\x12\x1b\n\ttarget_id\x18\x03\
+    \x20\x01(\tR\x08targetId\x12\x18\n\x07content\x18\x04\x20\x01(\x0cR\x07c\
+    ontent\x12\x1f\n\x0bcreate_time\x18\x05\x20\x01(\x03R\ncreateTime\"b\n\t\
+    ChatReply\x12,\n\x04code\x18\x01\x20\x01(\x0e2\x18.microchat.msg.ErrorCo\
+    deR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg\x12\x15\n\x06msg\
+    _id\x18\x03\x20\x01(\tR\x05msgIdb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::chatmsg::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(2);
+            messages.push(ChatMessage::generated_message_descriptor_data());
+            messages.push(ChatReply::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -176,6 +176,8 @@ pub struct Header {
     pub compress_size: i32,
     // @@protoc_insertion_point(field:microchat.msg.Header.reserved)
     pub reserved: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:microchat.msg.Header.seq)
+    pub seq: u32,
     // special fields
     // @@protoc_insertion_point(special_field:microchat.msg.Header.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -193,7 +195,7 @@ impl Header {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "compress",
@@ -215,6 +217,11 @@ impl Header {
             |m: &Header| { &m.reserved },
             |m: &mut Header| { &mut m.reserved },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "seq",
+            |m: &Header| { &m.seq },
+            |m: &mut Header| { &mut m.seq },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Header>(
             "Header",
             fields,
@@ -245,6 +252,9 @@ impl ::protobuf::Message for Header {
                 34 => {
                     self.reserved = ::std::option::Option::Some(is.read_string()?);
                 },
+                40 => {
+                    self.seq = is.read_uint32()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -269,6 +279,9 @@ impl ::protobuf::Message for Header {
         if let Some(v) = self.reserved.as_ref() {
             my_size += ::protobuf::rt::string_size(4, &v);
         }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.seq);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -287,6 +300,9 @@ impl ::protobuf::Message for Header {
         if let Some(v) = self.reserved.as_ref() {
             os.write_string(4, v)?;
         }
+        if self.seq != 0 {
+            os.write_uint32(5, self.seq)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -308,6 +324,7 @@ impl ::protobuf::Message for Header {
         self.origin_size = 0;
         self.compress_size = 0;
         self.reserved = ::std::option::Option::None;
+        self.seq = 0;
         self.special_fields.clear();
     }
 
@@ -317,6 +334,7 @@ impl ::protobuf::Message for Header {
             origin_size: 0,
             compress_size: 0,
             reserved: ::std::option::Option::None,
+            seq: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
@@ -340,6 +358,656 @@ impl ::protobuf::reflect::ProtobufValue for Header {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:microchat.msg.ErrorReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ErrorReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.msg.ErrorReply.msg_type)
+    pub msg_type: ::protobuf::EnumOrUnknown<MsgType>,
+    // @@protoc_insertion_point(field:microchat.msg.ErrorReply.code)
+    pub code: ::protobuf::EnumOrUnknown<ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.msg.ErrorReply.msg)
+    pub msg: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.msg.ErrorReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ErrorReply {
+    fn default() -> &'a ErrorReply {
+        <ErrorReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ErrorReply {
+    pub fn new() -> ErrorReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg_type",
+            |m: &ErrorReply| { &m.msg_type },
+            |m: &mut ErrorReply| { &mut m.msg_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &ErrorReply| { &m.code },
+            |m: &mut ErrorReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &ErrorReply| { &m.msg },
+            |m: &mut ErrorReply| { &mut m.msg },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ErrorReply>(
+            "ErrorReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ErrorReply {
+    const NAME: &'static str = "ErrorReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.msg_type = is.read_enum_or_unknown()?;
+                },
+                16 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.msg = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.msg_type != ::protobuf::EnumOrUnknown::new(MsgType::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.msg_type.value());
+        }
+        if self.code != ::protobuf::EnumOrUnknown::new(ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(2, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.msg);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.msg_type != ::protobuf::EnumOrUnknown::new(MsgType::UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.msg_type))?;
+        }
+        if self.code != ::protobuf::EnumOrUnknown::new(ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(3, &self.msg)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ErrorReply {
+        ErrorReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.msg_type = ::protobuf::EnumOrUnknown::new(MsgType::UNKNOWN);
+        self.code = ::protobuf::EnumOrUnknown::new(ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ErrorReply {
+        static instance: ErrorReply = ErrorReply {
+            msg_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ErrorReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ErrorReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ErrorReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ErrorReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.msg.Handshake)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Handshake {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.msg.Handshake.version)
+    pub version: u32,
+    // @@protoc_insertion_point(field:microchat.msg.Handshake.client_type)
+    pub client_type: ::protobuf::EnumOrUnknown<ClientType>,
+    // @@protoc_insertion_point(field:microchat.msg.Handshake.client_version)
+    pub client_version: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.msg.Handshake.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Handshake {
+    fn default() -> &'a Handshake {
+        <Handshake as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Handshake {
+    pub fn new() -> Handshake {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &Handshake| { &m.version },
+            |m: &mut Handshake| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "client_type",
+            |m: &Handshake| { &m.client_type },
+            |m: &mut Handshake| { &mut m.client_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "client_version",
+            |m: &Handshake| { &m.client_version },
+            |m: &mut Handshake| { &mut m.client_version },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Handshake>(
+            "Handshake",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Handshake {
+    const NAME: &'static str = "Handshake";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.version = is.read_uint32()?;
+                },
+                16 => {
+                    self.client_type = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.client_version = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.version);
+        }
+        if self.client_type != ::protobuf::EnumOrUnknown::new(ClientType::WINDOWS) {
+            my_size += ::protobuf::rt::int32_size(2, self.client_type.value());
+        }
+        if !self.client_version.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.client_version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.version != 0 {
+            os.write_uint32(1, self.version)?;
+        }
+        if self.client_type != ::protobuf::EnumOrUnknown::new(ClientType::WINDOWS) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.client_type))?;
+        }
+        if !self.client_version.is_empty() {
+            os.write_string(3, &self.client_version)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Handshake {
+        Handshake::new()
+    }
+
+    fn clear(&mut self) {
+        self.version = 0;
+        self.client_type = ::protobuf::EnumOrUnknown::new(ClientType::WINDOWS);
+        self.client_version.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Handshake {
+        static instance: Handshake = Handshake {
+            version: 0,
+            client_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            client_version: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Handshake {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Handshake").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Handshake {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Handshake {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.msg.HandshakeReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HandshakeReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.msg.HandshakeReply.code)
+    pub code: ::protobuf::EnumOrUnknown<ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.msg.HandshakeReply.msg)
+    pub msg: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.msg.HandshakeReply.server_version)
+    pub server_version: u32,
+    // @@protoc_insertion_point(field:microchat.msg.HandshakeReply.min_version)
+    pub min_version: u32,
+    // @@protoc_insertion_point(field:microchat.msg.HandshakeReply.upgrade_url)
+    pub upgrade_url: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.msg.HandshakeReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HandshakeReply {
+    fn default() -> &'a HandshakeReply {
+        <HandshakeReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HandshakeReply {
+    pub fn new() -> HandshakeReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &HandshakeReply| { &m.code },
+            |m: &mut HandshakeReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &HandshakeReply| { &m.msg },
+            |m: &mut HandshakeReply| { &mut m.msg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "server_version",
+            |m: &HandshakeReply| { &m.server_version },
+            |m: &mut HandshakeReply| { &mut m.server_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "min_version",
+            |m: &HandshakeReply| { &m.min_version },
+            |m: &mut HandshakeReply| { &mut m.min_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "upgrade_url",
+            |m: &HandshakeReply| { &m.upgrade_url },
+            |m: &mut HandshakeReply| { &mut m.upgrade_url },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HandshakeReply>(
+            "HandshakeReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HandshakeReply {
+    const NAME: &'static str = "HandshakeReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                24 => {
+                    self.server_version = is.read_uint32()?;
+                },
+                32 => {
+                    self.min_version = is.read_uint32()?;
+                },
+                42 => {
+                    self.upgrade_url = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if self.server_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.server_version);
+        }
+        if self.min_version != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.min_version);
+        }
+        if !self.upgrade_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.upgrade_url);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if self.server_version != 0 {
+            os.write_uint32(3, self.server_version)?;
+        }
+        if self.min_version != 0 {
+            os.write_uint32(4, self.min_version)?;
+        }
+        if !self.upgrade_url.is_empty() {
+            os.write_string(5, &self.upgrade_url)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HandshakeReply {
+        HandshakeReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.server_version = 0;
+        self.min_version = 0;
+        self.upgrade_url.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HandshakeReply {
+        static instance: HandshakeReply = HandshakeReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            server_version: 0,
+            min_version: 0,
+            upgrade_url: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HandshakeReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HandshakeReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HandshakeReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HandshakeReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.msg.KickUser)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct KickUser {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.msg.KickUser.client_type)
+    pub client_type: ::protobuf::EnumOrUnknown<ClientType>,
+    // @@protoc_insertion_point(field:microchat.msg.KickUser.reason)
+    pub reason: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.msg.KickUser.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a KickUser {
+    fn default() -> &'a KickUser {
+        <KickUser as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KickUser {
+    pub fn new() -> KickUser {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "client_type",
+            |m: &KickUser| { &m.client_type },
+            |m: &mut KickUser| { &mut m.client_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "reason",
+            |m: &KickUser| { &m.reason },
+            |m: &mut KickUser| { &mut m.reason },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KickUser>(
+            "KickUser",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for KickUser {
+    const NAME: &'static str = "KickUser";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.client_type = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.reason = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.client_type != ::protobuf::EnumOrUnknown::new(ClientType::WINDOWS) {
+            my_size += ::protobuf::rt::int32_size(1, self.client_type.value());
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.reason);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.client_type != ::protobuf::EnumOrUnknown::new(ClientType::WINDOWS) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.client_type))?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(2, &self.reason)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> KickUser {
+        KickUser::new()
+    }
+
+    fn clear(&mut self) {
+        self.client_type = ::protobuf::EnumOrUnknown::new(ClientType::WINDOWS);
+        self.reason.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static KickUser {
+        static instance: KickUser = KickUser {
+            client_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            reason: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for KickUser {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("KickUser").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for KickUser {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KickUser {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:microchat.msg.MsgType)
 pub enum MsgType {
@@ -367,6 +1035,12 @@ pub enum MsgType {
     CREATE_GROUP = 10,
     // @@protoc_insertion_point(enum_value:microchat.msg.MsgType.GET_GROUP_MEMBERS)
     GET_GROUP_MEMBERS = 11,
+    // @@protoc_insertion_point(enum_value:microchat.msg.MsgType.ERROR)
+    ERROR = 12,
+    // @@protoc_insertion_point(enum_value:microchat.msg.MsgType.ACK)
+    ACK = 13,
+    // @@protoc_insertion_point(enum_value:microchat.msg.MsgType.HANDSHAKE)
+    HANDSHAKE = 14,
     // @@protoc_insertion_point(enum_value:microchat.msg.MsgType.CHAT)
     CHAT = 50,
     // @@protoc_insertion_point(enum_value:microchat.msg.MsgType.MULTI_CHAT)
@@ -404,6 +1078,9 @@ impl ::protobuf::Enum for MsgType {
             9 => ::std::option::Option::Some(MsgType::MODIFY_PASSWORD),
             10 => ::std::option::Option::Some(MsgType::CREATE_GROUP),
             11 => ::std::option::Option::Some(MsgType::GET_GROUP_MEMBERS),
+            12 => ::std::option::Option::Some(MsgType::ERROR),
+            13 => ::std::option::Option::Some(MsgType::ACK),
+            14 => ::std::option::Option::Some(MsgType::HANDSHAKE),
             50 => ::std::option::Option::Some(MsgType::CHAT),
             51 => ::std::option::Option::Some(MsgType::MULTI_CHAT),
             52 => ::std::option::Option::Some(MsgType::KICK_USER),
@@ -429,6 +1106,9 @@ impl ::protobuf::Enum for MsgType {
             "MODIFY_PASSWORD" => ::std::option::Option::Some(MsgType::MODIFY_PASSWORD),
             "CREATE_GROUP" => ::std::option::Option::Some(MsgType::CREATE_GROUP),
             "GET_GROUP_MEMBERS" => ::std::option::Option::Some(MsgType::GET_GROUP_MEMBERS),
+            "ERROR" => ::std::option::Option::Some(MsgType::ERROR),
+            "ACK" => ::std::option::Option::Some(MsgType::ACK),
+            "HANDSHAKE" => ::std::option::Option::Some(MsgType::HANDSHAKE),
             "CHAT" => ::std::option::Option::Some(MsgType::CHAT),
             "MULTI_CHAT" => ::std::option::Option::Some(MsgType::MULTI_CHAT),
             "KICK_USER" => ::std::option::Option::Some(MsgType::KICK_USER),
@@ -453,6 +1133,9 @@ impl ::protobuf::Enum for MsgType {
         MsgType::MODIFY_PASSWORD,
         MsgType::CREATE_GROUP,
         MsgType::GET_GROUP_MEMBERS,
+        MsgType::ERROR,
+        MsgType::ACK,
+        MsgType::HANDSHAKE,
         MsgType::CHAT,
         MsgType::MULTI_CHAT,
         MsgType::KICK_USER,
@@ -483,13 +1166,16 @@ impl ::protobuf::EnumFull for MsgType {
             MsgType::MODIFY_PASSWORD => 9,
             MsgType::CREATE_GROUP => 10,
             MsgType::GET_GROUP_MEMBERS => 11,
-            MsgType::CHAT => 12,
-            MsgType::MULTI_CHAT => 13,
-            MsgType::KICK_USER => 14,
-            MsgType::REMOTE_DESKTOP => 15,
-            MsgType::UPDATE_TEAM_INFO => 16,
-            MsgType::MODIFY_FRIEND_MARKNAME => 17,
-            MsgType::MOVE_FRIEND_TO_OTHER_TEAM => 18,
+            MsgType::ERROR => 12,
+            MsgType::ACK => 13,
+            MsgType::HANDSHAKE => 14,
+            MsgType::CHAT => 15,
+            MsgType::MULTI_CHAT => 16,
+            MsgType::KICK_USER => 17,
+            MsgType::REMOTE_DESKTOP => 18,
+            MsgType::UPDATE_TEAM_INFO => 19,
+            MsgType::MODIFY_FRIEND_MARKNAME => 20,
+            MsgType::MOVE_FRIEND_TO_OTHER_TEAM => 21,
         };
         Self::enum_descriptor().value_by_index(index)
     }
@@ -831,6 +1517,8 @@ pub enum GroupOperationType {
     GROUP_OPERATION_DELETE = 2,
     // @@protoc_insertion_point(enum_value:microchat.msg.GroupOperationType.GROUP_OPERATION_MODIFY)
     GROUP_OPERATION_MODIFY = 3,
+    // @@protoc_insertion_point(enum_value:microchat.msg.GroupOperationType.GROUP_OPERATION_SORT)
+    GROUP_OPERATION_SORT = 4,
 }
 
 impl ::protobuf::Enum for GroupOperationType {
@@ -846,6 +1534,7 @@ impl ::protobuf::Enum for GroupOperationType {
             1 => ::std::option::Option::Some(GroupOperationType::GROUP_OPERATION_ADD),
             2 => ::std::option::Option::Some(GroupOperationType::GROUP_OPERATION_DELETE),
             3 => ::std::option::Option::Some(GroupOperationType::GROUP_OPERATION_MODIFY),
+            4 => ::std::option::Option::Some(GroupOperationType::GROUP_OPERATION_SORT),
             _ => ::std::option::Option::None
         }
     }
@@ -856,6 +1545,7 @@ impl ::protobuf::Enum for GroupOperationType {
             "GROUP_OPERATION_ADD" => ::std::option::Option::Some(GroupOperationType::GROUP_OPERATION_ADD),
             "GROUP_OPERATION_DELETE" => ::std::option::Option::Some(GroupOperationType::GROUP_OPERATION_DELETE),
             "GROUP_OPERATION_MODIFY" => ::std::option::Option::Some(GroupOperationType::GROUP_OPERATION_MODIFY),
+            "GROUP_OPERATION_SORT" => ::std::option::Option::Some(GroupOperationType::GROUP_OPERATION_SORT),
             _ => ::std::option::Option::None
         }
     }
@@ -865,6 +1555,7 @@ impl ::protobuf::Enum for GroupOperationType {
         GroupOperationType::GROUP_OPERATION_ADD,
         GroupOperationType::GROUP_OPERATION_DELETE,
         GroupOperationType::GROUP_OPERATION_MODIFY,
+        GroupOperationType::GROUP_OPERATION_SORT,
     ];
 }
 
@@ -1027,40 +1718,55 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \n\rchatmsg.proto\x12\rmicrochat.msg\"t\n\nOnlineType\x121\n\x06client\
     \x18\x01\x20\x01(\x0e2\x19.microchat.msg.ClientTypeR\x06client\x123\n\
     \x06status\x18\x02\x20\x01(\x0e2\x1b.microchat.msg.OnlineStatusR\x06stat\
-    us\"\x98\x01\n\x06Header\x12\x1a\n\x08compress\x18\x01\x20\x01(\x08R\x08\
+    us\"\xaa\x01\n\x06Header\x12\x1a\n\x08compress\x18\x01\x20\x01(\x08R\x08\
     compress\x12\x1f\n\x0borigin_size\x18\x02\x20\x01(\x05R\noriginSize\x12#\
     \n\rcompress_size\x18\x03\x20\x01(\x05R\x0ccompressSize\x12\x1f\n\x08res\
-    erved\x18\x04\x20\x01(\tH\0R\x08reserved\x88\x01\x01B\x0b\n\t_reserved*\
-    \xf1\x02\n\x07MsgType\x12\x0b\n\x07UNKNOWN\x10\0\x12\r\n\tHEARTBEAT\x10\
-    \x01\x12\x0c\n\x08REGISTER\x10\x02\x12\t\n\x05LOGIN\x10\x03\x12\x14\n\
-    \x10GET_OFRIEND_LIST\x10\x04\x12\r\n\tFIND_USER\x10\x05\x12\x12\n\x0eOPE\
-    RATE_FRIEND\x10\x06\x12\x16\n\x12USER_STATUS_CHANGE\x10\x07\x12\x14\n\
-    \x10UPDATE_USER_INFO\x10\x08\x12\x13\n\x0fMODIFY_PASSWORD\x10\t\x12\x10\
-    \n\x0cCREATE_GROUP\x10\n\x12\x15\n\x11GET_GROUP_MEMBERS\x10\x0b\x12\x08\
-    \n\x04CHAT\x102\x12\x0e\n\nMULTI_CHAT\x103\x12\r\n\tKICK_USER\x104\x12\
-    \x12\n\x0eREMOTE_DESKTOP\x105\x12\x14\n\x10UPDATE_TEAM_INFO\x106\x12\x1a\
-    \n\x16MODIFY_FRIEND_MARKNAME\x107\x12\x1d\n\x19MOVE_FRIEND_TO_OTHER_TEAM\
-    \x108*M\n\nClientType\x12\x0b\n\x07WINDOWS\x10\0\x12\t\n\x05LINUX\x10\
-    \x01\x12\x07\n\x03MAC\x10\x02\x12\x0b\n\x07ANDROID\x10\x03\x12\x07\n\x03\
-    IOS\x10\x04\x12\x08\n\x04IPAD\x10\x05*g\n\x0cOnlineStatus\x12\x0b\n\x07O\
-    FFLINE\x10\0\x12\r\n\tINVISIBLE\x10\x01\x12\x08\n\x04WIFI\x10\x02\x12\
-    \x0f\n\x0bCELLULAR_3G\x10\x03\x12\x0f\n\x0bCELLULAR_4G\x10\x04\x12\x0f\n\
-    \x0bCELLULAR_5G\x10\x05*\x97\x01\n\x13FriendOperationType\x12\x15\n\x11O\
-    PERATION_UNKNOWN\x10\0\x12\x12\n\x0eSEND_ADD_APPLY\x10\x01\x12\x12\n\x0e\
-    RECV_ADD_APPLY\x10\x02\x12\x13\n\x0fREPLY_ADD_APPLY\x10\x03\x12\x15\n\
-    \x11SEND_DELETE_APPLY\x10\x04\x12\x15\n\x11RECV_DELETE_APPLY\x10\x05*T\n\
-    \x18friendOperationApplyType\x12\x11\n\rAPPLY_UNKNOWN\x10\0\x12\x11\n\rA\
-    PPLY_REFUSED\x10\x01\x12\x12\n\x0eAPPLY_ACCEPTED\x10\x02*\x82\x01\n\x12G\
-    roupOperationType\x12\x1b\n\x17GROUP_OPERATION_UNKNOWN\x10\0\x12\x17\n\
-    \x13GROUP_OPERATION_ADD\x10\x01\x12\x1a\n\x16GROUP_OPERATION_DELETE\x10\
-    \x02\x12\x1a\n\x16GROUP_OPERATION_MODIFY\x10\x03*\xa0\x02\n\tErrorCode\
-    \x12\x0f\n\x0bERR_UNKNOWN\x10\0\x12\n\n\x06ERR_OK\x10\x01\x12\x11\n\rERR\
-    _NOT_LOGIN\x10\x02\x12\x10\n\x0cERR_REG_FAIL\x10d\x12\x13\n\x0fERR_REG_A\
-    LREADY\x10e\x12\x0f\n\x0bERR_NOT_REG\x10f\x12\x13\n\x0fERR_INVALID_PSW\
-    \x10g\x12\x19\n\x15ERR_UPD_USERINFO_FAIL\x10h\x12\x17\n\x13ERR_MODIFY_PS\
-    W_FAIL\x10i\x12\x16\n\x12ERR_CRE_GROUP_FAIL\x10j\x12\x13\n\x0fERR_TOO_OL\
-    D_VER\x10k\x12\x1c\n\x18ERR_MODIFY_MARKNAME_FAIL\x10l\x12\x17\n\x13ERR_G\
-    ROUPNAME_EXIST\x10mb\x06proto3\
+    erved\x18\x04\x20\x01(\tH\0R\x08reserved\x88\x01\x01\x12\x10\n\x03seq\
+    \x18\x05\x20\x01(\rR\x03seqB\x0b\n\t_reserved\"\x7f\n\nErrorReply\x121\n\
+    \x08msg_type\x18\x01\x20\x01(\x0e2\x16.microchat.msg.MsgTypeR\x07msgType\
+    \x12,\n\x04code\x18\x02\x20\x01(\x0e2\x18.microchat.msg.ErrorCodeR\x04co\
+    de\x12\x10\n\x03msg\x18\x03\x20\x01(\tR\x03msg\"\x88\x01\n\tHandshake\
+    \x12\x18\n\x07version\x18\x01\x20\x01(\rR\x07version\x12:\n\x0bclient_ty\
+    pe\x18\x02\x20\x01(\x0e2\x19.microchat.msg.ClientTypeR\nclientType\x12%\
+    \n\x0eclient_version\x18\x03\x20\x01(\tR\rclientVersion\"\xb9\x01\n\x0eH\
+    andshakeReply\x12,\n\x04code\x18\x01\x20\x01(\x0e2\x18.microchat.msg.Err\
+    orCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg\x12%\n\x0ese\
+    rver_version\x18\x03\x20\x01(\rR\rserverVersion\x12\x1f\n\x0bmin_version\
+    \x18\x04\x20\x01(\rR\nminVersion\x12\x1f\n\x0bupgrade_url\x18\x05\x20\
+    \x01(\tR\nupgradeUrl\"^\n\x08KickUser\x12:\n\x0bclient_type\x18\x01\x20\
+    \x01(\x0e2\x19.microchat.msg.ClientTypeR\nclientType\x12\x16\n\x06reason\
+    \x18\x02\x20\x01(\tR\x06reason*\x94\x03\n\x07MsgType\x12\x0b\n\x07UNKNOW\
+    N\x10\0\x12\r\n\tHEARTBEAT\x10\x01\x12\x0c\n\x08REGISTER\x10\x02\x12\t\n\
+    \x05LOGIN\x10\x03\x12\x14\n\x10GET_OFRIEND_LIST\x10\x04\x12\r\n\tFIND_US\
+    ER\x10\x05\x12\x12\n\x0eOPERATE_FRIEND\x10\x06\x12\x16\n\x12USER_STATUS_\
+    CHANGE\x10\x07\x12\x14\n\x10UPDATE_USER_INFO\x10\x08\x12\x13\n\x0fMODIFY\
+    _PASSWORD\x10\t\x12\x10\n\x0cCREATE_GROUP\x10\n\x12\x15\n\x11GET_GROUP_M\
+    EMBERS\x10\x0b\x12\t\n\x05ERROR\x10\x0c\x12\x07\n\x03ACK\x10\r\x12\r\n\t\
+    HANDSHAKE\x10\x0e\x12\x08\n\x04CHAT\x102\x12\x0e\n\nMULTI_CHAT\x103\x12\
+    \r\n\tKICK_USER\x104\x12\x12\n\x0eREMOTE_DESKTOP\x105\x12\x14\n\x10UPDAT\
+    E_TEAM_INFO\x106\x12\x1a\n\x16MODIFY_FRIEND_MARKNAME\x107\x12\x1d\n\x19M\
+    OVE_FRIEND_TO_OTHER_TEAM\x108*M\n\nClientType\x12\x0b\n\x07WINDOWS\x10\0\
+    \x12\t\n\x05LINUX\x10\x01\x12\x07\n\x03MAC\x10\x02\x12\x0b\n\x07ANDROID\
+    \x10\x03\x12\x07\n\x03IOS\x10\x04\x12\x08\n\x04IPAD\x10\x05*g\n\x0cOnlin\
+    eStatus\x12\x0b\n\x07OFFLINE\x10\0\x12\r\n\tINVISIBLE\x10\x01\x12\x08\n\
+    \x04WIFI\x10\x02\x12\x0f\n\x0bCELLULAR_3G\x10\x03\x12\x0f\n\x0bCELLULAR_\
+    4G\x10\x04\x12\x0f\n\x0bCELLULAR_5G\x10\x05*\x97\x01\n\x13FriendOperatio\
+    nType\x12\x15\n\x11OPERATION_UNKNOWN\x10\0\x12\x12\n\x0eSEND_ADD_APPLY\
+    \x10\x01\x12\x12\n\x0eRECV_ADD_APPLY\x10\x02\x12\x13\n\x0fREPLY_ADD_APPL\
+    Y\x10\x03\x12\x15\n\x11SEND_DELETE_APPLY\x10\x04\x12\x15\n\x11RECV_DELET\
+    E_APPLY\x10\x05*T\n\x18friendOperationApplyType\x12\x11\n\rAPPLY_UNKNOWN\
+    \x10\0\x12\x11\n\rAPPLY_REFUSED\x10\x01\x12\x12\n\x0eAPPLY_ACCEPTED\x10\
+    \x02*\x9c\x01\n\x12GroupOperationType\x12\x1b\n\x17GROUP_OPERATION_UNKNO\
+    WN\x10\0\x12\x17\n\x13GROUP_OPERATION_ADD\x10\x01\x12\x1a\n\x16GROUP_OPE\
+    RATION_DELETE\x10\x02\x12\x1a\n\x16GROUP_OPERATION_MODIFY\x10\x03\x12\
+    \x18\n\x14GROUP_OPERATION_SORT\x10\x04*\xa0\x02\n\tErrorCode\x12\x0f\n\
+    \x0bERR_UNKNOWN\x10\0\x12\n\n\x06ERR_OK\x10\x01\x12\x11\n\rERR_NOT_LOGIN\
+    \x10\x02\x12\x10\n\x0cERR_REG_FAIL\x10d\x12\x13\n\x0fERR_REG_ALREADY\x10\
+    e\x12\x0f\n\x0bERR_NOT_REG\x10f\x12\x13\n\x0fERR_INVALID_PSW\x10g\x12\
+    \x19\n\x15ERR_UPD_USERINFO_FAIL\x10h\x12\x17\n\x13ERR_MODIFY_PSW_FAIL\
+    \x10i\x12\x16\n\x12ERR_CRE_GROUP_FAIL\x10j\x12\x13\n\x0fERR_TOO_OLD_VER\
+    \x10k\x12\x1c\n\x18ERR_MODIFY_MARKNAME_FAIL\x10l\x12\x17\n\x13ERR_GROUPN\
+    AME_EXIST\x10mb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -1078,9 +1784,13 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(2);
+            let mut messages = ::std::vec::Vec::with_capacity(6);
             messages.push(OnlineType::generated_message_descriptor_data());
             messages.push(Header::generated_message_descriptor_data());
+            messages.push(ErrorReply::generated_message_descriptor_data());
+            messages.push(Handshake::generated_message_descriptor_data());
+            messages.push(HandshakeReply::generated_message_descriptor_data());
+            messages.push(KickUser::generated_message_descriptor_data());
             let mut enums = ::std::vec::Vec::with_capacity(7);
             enums.push(MsgType::generated_enum_descriptor_data());
             enums.push(ClientType::generated_enum_descriptor_data());
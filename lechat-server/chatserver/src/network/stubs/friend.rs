@@ -0,0 +1,1677 @@
+// This file is generated by rust-protobuf 3.5.1. Do not edit
+// .proto file is parsed by protoc 25.3
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `friend.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_5_1;
+
+// @@protoc_insertion_point(message:microchat.friend.FriendApply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct FriendApply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.FriendApply.apply_id)
+    pub apply_id: i64,
+    // @@protoc_insertion_point(field:microchat.friend.FriendApply.from)
+    pub from: ::protobuf::MessageField<super::user::UserProfile>,
+    // @@protoc_insertion_point(field:microchat.friend.FriendApply.to)
+    pub to: ::protobuf::MessageField<super::user::UserProfile>,
+    // @@protoc_insertion_point(field:microchat.friend.FriendApply.message)
+    pub message: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.FriendApply.status)
+    pub status: ::protobuf::EnumOrUnknown<super::chatmsg::FriendOperationApplyType>,
+    // @@protoc_insertion_point(field:microchat.friend.FriendApply.create_time)
+    pub create_time: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.FriendApply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FriendApply {
+    fn default() -> &'a FriendApply {
+        <FriendApply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FriendApply {
+    pub fn new() -> FriendApply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "apply_id",
+            |m: &FriendApply| { &m.apply_id },
+            |m: &mut FriendApply| { &mut m.apply_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::user::UserProfile>(
+            "from",
+            |m: &FriendApply| { &m.from },
+            |m: &mut FriendApply| { &mut m.from },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::user::UserProfile>(
+            "to",
+            |m: &FriendApply| { &m.to },
+            |m: &mut FriendApply| { &mut m.to },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &FriendApply| { &m.message },
+            |m: &mut FriendApply| { &mut m.message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &FriendApply| { &m.status },
+            |m: &mut FriendApply| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "create_time",
+            |m: &FriendApply| { &m.create_time },
+            |m: &mut FriendApply| { &mut m.create_time },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FriendApply>(
+            "FriendApply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FriendApply {
+    const NAME: &'static str = "FriendApply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.apply_id = is.read_int64()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.from)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.to)?;
+                },
+                34 => {
+                    self.message = is.read_string()?;
+                },
+                40 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                48 => {
+                    self.create_time = is.read_int64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.apply_id != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.apply_id);
+        }
+        if let Some(v) = self.from.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.to.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.message);
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationApplyType::APPLY_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(5, self.status.value());
+        }
+        if self.create_time != 0 {
+            my_size += ::protobuf::rt::int64_size(6, self.create_time);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.apply_id != 0 {
+            os.write_int64(1, self.apply_id)?;
+        }
+        if let Some(v) = self.from.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.to.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(4, &self.message)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationApplyType::APPLY_UNKNOWN) {
+            os.write_enum(5, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if self.create_time != 0 {
+            os.write_int64(6, self.create_time)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FriendApply {
+        FriendApply::new()
+    }
+
+    fn clear(&mut self) {
+        self.apply_id = 0;
+        self.from.clear();
+        self.to.clear();
+        self.message.clear();
+        self.status = ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationApplyType::APPLY_UNKNOWN);
+        self.create_time = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FriendApply {
+        static instance: FriendApply = FriendApply {
+            apply_id: 0,
+            from: ::protobuf::MessageField::none(),
+            to: ::protobuf::MessageField::none(),
+            message: ::std::string::String::new(),
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            create_time: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FriendApply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FriendApply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FriendApply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FriendApply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.friend.OperateFriendRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct OperateFriendRequest {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendRequest.type)
+    pub type_: ::protobuf::EnumOrUnknown<super::chatmsg::FriendOperationType>,
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendRequest.user_id)
+    pub user_id: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendRequest.message)
+    pub message: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendRequest.apply_id)
+    pub apply_id: i64,
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendRequest.accept)
+    pub accept: ::protobuf::EnumOrUnknown<super::chatmsg::FriendOperationApplyType>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.OperateFriendRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a OperateFriendRequest {
+    fn default() -> &'a OperateFriendRequest {
+        <OperateFriendRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OperateFriendRequest {
+    pub fn new() -> OperateFriendRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &OperateFriendRequest| { &m.type_ },
+            |m: &mut OperateFriendRequest| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "user_id",
+            |m: &OperateFriendRequest| { &m.user_id },
+            |m: &mut OperateFriendRequest| { &mut m.user_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &OperateFriendRequest| { &m.message },
+            |m: &mut OperateFriendRequest| { &mut m.message },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "apply_id",
+            |m: &OperateFriendRequest| { &m.apply_id },
+            |m: &mut OperateFriendRequest| { &mut m.apply_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "accept",
+            |m: &OperateFriendRequest| { &m.accept },
+            |m: &mut OperateFriendRequest| { &mut m.accept },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OperateFriendRequest>(
+            "OperateFriendRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for OperateFriendRequest {
+    const NAME: &'static str = "OperateFriendRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.user_id = is.read_string()?;
+                },
+                26 => {
+                    self.message = is.read_string()?;
+                },
+                32 => {
+                    self.apply_id = is.read_int64()?;
+                },
+                40 => {
+                    self.accept = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.type_ != ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationType::OPERATION_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.type_.value());
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.message);
+        }
+        if self.apply_id != 0 {
+            my_size += ::protobuf::rt::int64_size(4, self.apply_id);
+        }
+        if self.accept != ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationApplyType::APPLY_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(5, self.accept.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.type_ != ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationType::OPERATION_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(3, &self.message)?;
+        }
+        if self.apply_id != 0 {
+            os.write_int64(4, self.apply_id)?;
+        }
+        if self.accept != ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationApplyType::APPLY_UNKNOWN) {
+            os.write_enum(5, ::protobuf::EnumOrUnknown::value(&self.accept))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> OperateFriendRequest {
+        OperateFriendRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationType::OPERATION_UNKNOWN);
+        self.user_id.clear();
+        self.message.clear();
+        self.apply_id = 0;
+        self.accept = ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationApplyType::APPLY_UNKNOWN);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static OperateFriendRequest {
+        static instance: OperateFriendRequest = OperateFriendRequest {
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            user_id: ::std::string::String::new(),
+            message: ::std::string::String::new(),
+            apply_id: 0,
+            accept: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for OperateFriendRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("OperateFriendRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for OperateFriendRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OperateFriendRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.friend.OperateFriendReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct OperateFriendReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendReply.code)
+    pub code: ::protobuf::EnumOrUnknown<super::chatmsg::ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendReply.msg)
+    pub msg: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendReply.type)
+    pub type_: ::protobuf::EnumOrUnknown<super::chatmsg::FriendOperationType>,
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendReply.apply)
+    pub apply: ::protobuf::MessageField<FriendApply>,
+    // @@protoc_insertion_point(field:microchat.friend.OperateFriendReply.user)
+    pub user: ::protobuf::MessageField<super::user::UserProfile>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.OperateFriendReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a OperateFriendReply {
+    fn default() -> &'a OperateFriendReply {
+        <OperateFriendReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OperateFriendReply {
+    pub fn new() -> OperateFriendReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &OperateFriendReply| { &m.code },
+            |m: &mut OperateFriendReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &OperateFriendReply| { &m.msg },
+            |m: &mut OperateFriendReply| { &mut m.msg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &OperateFriendReply| { &m.type_ },
+            |m: &mut OperateFriendReply| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, FriendApply>(
+            "apply",
+            |m: &OperateFriendReply| { &m.apply },
+            |m: &mut OperateFriendReply| { &mut m.apply },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::user::UserProfile>(
+            "user",
+            |m: &OperateFriendReply| { &m.user },
+            |m: &mut OperateFriendReply| { &mut m.user },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OperateFriendReply>(
+            "OperateFriendReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for OperateFriendReply {
+    const NAME: &'static str = "OperateFriendReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                24 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.apply)?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.user)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationType::OPERATION_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(3, self.type_.value());
+        }
+        if let Some(v) = self.apply.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.user.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationType::OPERATION_UNKNOWN) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        if let Some(v) = self.apply.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.user.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> OperateFriendReply {
+        OperateFriendReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.type_ = ::protobuf::EnumOrUnknown::new(super::chatmsg::FriendOperationType::OPERATION_UNKNOWN);
+        self.apply.clear();
+        self.user.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static OperateFriendReply {
+        static instance: OperateFriendReply = OperateFriendReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            apply: ::protobuf::MessageField::none(),
+            user: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for OperateFriendReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("OperateFriendReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for OperateFriendReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OperateFriendReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.friend.FriendInfo)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct FriendInfo {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.FriendInfo.profile)
+    pub profile: ::protobuf::MessageField<super::user::UserProfile>,
+    // @@protoc_insertion_point(field:microchat.friend.FriendInfo.mark_name)
+    pub mark_name: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.FriendInfo.status)
+    pub status: ::protobuf::EnumOrUnknown<super::chatmsg::OnlineStatus>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.FriendInfo.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FriendInfo {
+    fn default() -> &'a FriendInfo {
+        <FriendInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FriendInfo {
+    pub fn new() -> FriendInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::user::UserProfile>(
+            "profile",
+            |m: &FriendInfo| { &m.profile },
+            |m: &mut FriendInfo| { &mut m.profile },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "mark_name",
+            |m: &FriendInfo| { &m.mark_name },
+            |m: &mut FriendInfo| { &mut m.mark_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &FriendInfo| { &m.status },
+            |m: &mut FriendInfo| { &mut m.status },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FriendInfo>(
+            "FriendInfo",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FriendInfo {
+    const NAME: &'static str = "FriendInfo";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.profile)?;
+                },
+                18 => {
+                    self.mark_name = is.read_string()?;
+                },
+                24 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.profile.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.mark_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.mark_name);
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(super::chatmsg::OnlineStatus::OFFLINE) {
+            my_size += ::protobuf::rt::int32_size(3, self.status.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.profile.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.mark_name.is_empty() {
+            os.write_string(2, &self.mark_name)?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(super::chatmsg::OnlineStatus::OFFLINE) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FriendInfo {
+        FriendInfo::new()
+    }
+
+    fn clear(&mut self) {
+        self.profile.clear();
+        self.mark_name.clear();
+        self.status = ::protobuf::EnumOrUnknown::new(super::chatmsg::OnlineStatus::OFFLINE);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FriendInfo {
+        static instance: FriendInfo = FriendInfo {
+            profile: ::protobuf::MessageField::none(),
+            mark_name: ::std::string::String::new(),
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FriendInfo {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FriendInfo").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FriendInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FriendInfo {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.friend.FriendTeam)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct FriendTeam {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.FriendTeam.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.FriendTeam.friends)
+    pub friends: ::std::vec::Vec<FriendInfo>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.FriendTeam.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FriendTeam {
+    fn default() -> &'a FriendTeam {
+        <FriendTeam as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FriendTeam {
+    pub fn new() -> FriendTeam {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &FriendTeam| { &m.name },
+            |m: &mut FriendTeam| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "friends",
+            |m: &FriendTeam| { &m.friends },
+            |m: &mut FriendTeam| { &mut m.friends },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FriendTeam>(
+            "FriendTeam",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FriendTeam {
+    const NAME: &'static str = "FriendTeam";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                18 => {
+                    self.friends.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.friends {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.friends {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FriendTeam {
+        FriendTeam::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.friends.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FriendTeam {
+        static instance: FriendTeam = FriendTeam {
+            name: ::std::string::String::new(),
+            friends: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FriendTeam {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FriendTeam").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FriendTeam {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FriendTeam {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.friend.GetFriendListRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetFriendListRequest {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.GetFriendListRequest.since)
+    pub since: i64,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.GetFriendListRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetFriendListRequest {
+    fn default() -> &'a GetFriendListRequest {
+        <GetFriendListRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetFriendListRequest {
+    pub fn new() -> GetFriendListRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "since",
+            |m: &GetFriendListRequest| { &m.since },
+            |m: &mut GetFriendListRequest| { &mut m.since },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetFriendListRequest>(
+            "GetFriendListRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetFriendListRequest {
+    const NAME: &'static str = "GetFriendListRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.since = is.read_int64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.since != 0 {
+            my_size += ::protobuf::rt::int64_size(1, self.since);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.since != 0 {
+            os.write_int64(1, self.since)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetFriendListRequest {
+        GetFriendListRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.since = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetFriendListRequest {
+        static instance: GetFriendListRequest = GetFriendListRequest {
+            since: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetFriendListRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetFriendListRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetFriendListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetFriendListRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.friend.GetFriendListReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetFriendListReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.GetFriendListReply.code)
+    pub code: ::protobuf::EnumOrUnknown<super::chatmsg::ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.friend.GetFriendListReply.msg)
+    pub msg: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.GetFriendListReply.version)
+    pub version: i64,
+    // @@protoc_insertion_point(field:microchat.friend.GetFriendListReply.teams)
+    pub teams: ::std::vec::Vec<FriendTeam>,
+    // @@protoc_insertion_point(field:microchat.friend.GetFriendListReply.removed)
+    pub removed: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.GetFriendListReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetFriendListReply {
+    fn default() -> &'a GetFriendListReply {
+        <GetFriendListReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetFriendListReply {
+    pub fn new() -> GetFriendListReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &GetFriendListReply| { &m.code },
+            |m: &mut GetFriendListReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &GetFriendListReply| { &m.msg },
+            |m: &mut GetFriendListReply| { &mut m.msg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &GetFriendListReply| { &m.version },
+            |m: &mut GetFriendListReply| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "teams",
+            |m: &GetFriendListReply| { &m.teams },
+            |m: &mut GetFriendListReply| { &mut m.teams },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "removed",
+            |m: &GetFriendListReply| { &m.removed },
+            |m: &mut GetFriendListReply| { &mut m.removed },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetFriendListReply>(
+            "GetFriendListReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetFriendListReply {
+    const NAME: &'static str = "GetFriendListReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                24 => {
+                    self.version = is.read_int64()?;
+                },
+                34 => {
+                    self.teams.push(is.read_message()?);
+                },
+                42 => {
+                    self.removed.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::int64_size(3, self.version);
+        }
+        for value in &self.teams {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        for value in &self.removed {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if self.version != 0 {
+            os.write_int64(3, self.version)?;
+        }
+        for v in &self.teams {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        for v in &self.removed {
+            os.write_string(5, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetFriendListReply {
+        GetFriendListReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.version = 0;
+        self.teams.clear();
+        self.removed.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetFriendListReply {
+        static instance: GetFriendListReply = GetFriendListReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            version: 0,
+            teams: ::std::vec::Vec::new(),
+            removed: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetFriendListReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetFriendListReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetFriendListReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetFriendListReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.friend.UpdateTeamInfoRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UpdateTeamInfoRequest {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.UpdateTeamInfoRequest.type)
+    pub type_: ::protobuf::EnumOrUnknown<super::chatmsg::GroupOperationType>,
+    // @@protoc_insertion_point(field:microchat.friend.UpdateTeamInfoRequest.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.UpdateTeamInfoRequest.new_name)
+    pub new_name: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.UpdateTeamInfoRequest.names)
+    pub names: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.UpdateTeamInfoRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateTeamInfoRequest {
+    fn default() -> &'a UpdateTeamInfoRequest {
+        <UpdateTeamInfoRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateTeamInfoRequest {
+    pub fn new() -> UpdateTeamInfoRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &UpdateTeamInfoRequest| { &m.type_ },
+            |m: &mut UpdateTeamInfoRequest| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &UpdateTeamInfoRequest| { &m.name },
+            |m: &mut UpdateTeamInfoRequest| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "new_name",
+            |m: &UpdateTeamInfoRequest| { &m.new_name },
+            |m: &mut UpdateTeamInfoRequest| { &mut m.new_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "names",
+            |m: &UpdateTeamInfoRequest| { &m.names },
+            |m: &mut UpdateTeamInfoRequest| { &mut m.names },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UpdateTeamInfoRequest>(
+            "UpdateTeamInfoRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UpdateTeamInfoRequest {
+    const NAME: &'static str = "UpdateTeamInfoRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.name = is.read_string()?;
+                },
+                26 => {
+                    self.new_name = is.read_string()?;
+                },
+                34 => {
+                    self.names.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.type_ != ::protobuf::EnumOrUnknown::new(super::chatmsg::GroupOperationType::GROUP_OPERATION_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.type_.value());
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if !self.new_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.new_name);
+        }
+        for value in &self.names {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.type_ != ::protobuf::EnumOrUnknown::new(super::chatmsg::GroupOperationType::GROUP_OPERATION_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if !self.new_name.is_empty() {
+            os.write_string(3, &self.new_name)?;
+        }
+        for v in &self.names {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UpdateTeamInfoRequest {
+        UpdateTeamInfoRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(super::chatmsg::GroupOperationType::GROUP_OPERATION_UNKNOWN);
+        self.name.clear();
+        self.new_name.clear();
+        self.names.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UpdateTeamInfoRequest {
+        static instance: UpdateTeamInfoRequest = UpdateTeamInfoRequest {
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            name: ::std::string::String::new(),
+            new_name: ::std::string::String::new(),
+            names: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UpdateTeamInfoRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UpdateTeamInfoRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UpdateTeamInfoRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateTeamInfoRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.friend.UpdateTeamInfoReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UpdateTeamInfoReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.friend.UpdateTeamInfoReply.code)
+    pub code: ::protobuf::EnumOrUnknown<super::chatmsg::ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.friend.UpdateTeamInfoReply.msg)
+    pub msg: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.friend.UpdateTeamInfoReply.type)
+    pub type_: ::protobuf::EnumOrUnknown<super::chatmsg::GroupOperationType>,
+    // @@protoc_insertion_point(field:microchat.friend.UpdateTeamInfoReply.teams)
+    pub teams: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.friend.UpdateTeamInfoReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateTeamInfoReply {
+    fn default() -> &'a UpdateTeamInfoReply {
+        <UpdateTeamInfoReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateTeamInfoReply {
+    pub fn new() -> UpdateTeamInfoReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &UpdateTeamInfoReply| { &m.code },
+            |m: &mut UpdateTeamInfoReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &UpdateTeamInfoReply| { &m.msg },
+            |m: &mut UpdateTeamInfoReply| { &mut m.msg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &UpdateTeamInfoReply| { &m.type_ },
+            |m: &mut UpdateTeamInfoReply| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "teams",
+            |m: &UpdateTeamInfoReply| { &m.teams },
+            |m: &mut UpdateTeamInfoReply| { &mut m.teams },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UpdateTeamInfoReply>(
+            "UpdateTeamInfoReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UpdateTeamInfoReply {
+    const NAME: &'static str = "UpdateTeamInfoReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                24 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                34 => {
+                    self.teams.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(super::chatmsg::GroupOperationType::GROUP_OPERATION_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(3, self.type_.value());
+        }
+        for value in &self.teams {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if self.type_ != ::protobuf::EnumOrUnknown::new(super::chatmsg::GroupOperationType::GROUP_OPERATION_UNKNOWN) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        for v in &self.teams {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UpdateTeamInfoReply {
+        UpdateTeamInfoReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.type_ = ::protobuf::EnumOrUnknown::new(super::chatmsg::GroupOperationType::GROUP_OPERATION_UNKNOWN);
+        self.teams.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UpdateTeamInfoReply {
+        static instance: UpdateTeamInfoReply = UpdateTeamInfoReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            teams: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UpdateTeamInfoReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UpdateTeamInfoReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UpdateTeamInfoReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateTeamInfoReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cfriend.proto\x12\x10microchat.friend\x1a\rchatmsg.proto\x1a\nuser.\
+    proto\"\x82\x02\n\x0bFriendApply\x12\x19\n\x08apply_id\x18\x01\x20\x01(\
+    \x03R\x07applyId\x12/\n\x04from\x18\x02\x20\x01(\x0b2\x1b.microchat.user\
+    .UserProfileR\x04from\x12+\n\x02to\x18\x03\x20\x01(\x0b2\x1b.microchat.u\
+    ser.UserProfileR\x02to\x12\x18\n\x07message\x18\x04\x20\x01(\tR\x07messa\
+    ge\x12?\n\x06status\x18\x05\x20\x01(\x0e2'.microchat.msg.friendOperation\
+    ApplyTypeR\x06status\x12\x1f\n\x0bcreate_time\x18\x06\x20\x01(\x03R\ncre\
+    ateTime\"\xdd\x01\n\x14OperateFriendRequest\x126\n\x04type\x18\x01\x20\
+    \x01(\x0e2\".microchat.msg.FriendOperationTypeR\x04type\x12\x17\n\x07use\
+    r_id\x18\x02\x20\x01(\tR\x06userId\x12\x18\n\x07message\x18\x03\x20\x01(\
+    \tR\x07message\x12\x19\n\x08apply_id\x18\x04\x20\x01(\x03R\x07applyId\
+    \x12?\n\x06accept\x18\x05\x20\x01(\x0e2'.microchat.msg.friendOperationAp\
+    plyTypeR\x06accept\"\xf2\x01\n\x12OperateFriendReply\x12,\n\x04code\x18\
+    \x01\x20\x01(\x0e2\x18.microchat.msg.ErrorCodeR\x04code\x12\x10\n\x03msg\
+    \x18\x02\x20\x01(\tR\x03msg\x126\n\x04type\x18\x03\x20\x01(\x0e2\".micro\
+    chat.msg.FriendOperationTypeR\x04type\x123\n\x05apply\x18\x04\x20\x01(\
+    \x0b2\x1d.microchat.friend.FriendApplyR\x05apply\x12/\n\x04user\x18\x05\
+    \x20\x01(\x0b2\x1b.microchat.user.UserProfileR\x04user\"\x95\x01\n\nFrie\
+    ndInfo\x125\n\x07profile\x18\x01\x20\x01(\x0b2\x1b.microchat.user.UserPr\
+    ofileR\x07profile\x12\x1b\n\tmark_name\x18\x02\x20\x01(\tR\x08markName\
+    \x123\n\x06status\x18\x03\x20\x01(\x0e2\x1b.microchat.msg.OnlineStatusR\
+    \x06status\"X\n\nFriendTeam\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04nam\
+    e\x126\n\x07friends\x18\x02\x20\x03(\x0b2\x1c.microchat.friend.FriendInf\
+    oR\x07friends\",\n\x14GetFriendListRequest\x12\x14\n\x05since\x18\x01\
+    \x20\x01(\x03R\x05since\"\xbc\x01\n\x12GetFriendListReply\x12,\n\x04code\
+    \x18\x01\x20\x01(\x0e2\x18.microchat.msg.ErrorCodeR\x04code\x12\x10\n\
+    \x03msg\x18\x02\x20\x01(\tR\x03msg\x12\x18\n\x07version\x18\x03\x20\x01(\
+    \x03R\x07version\x122\n\x05teams\x18\x04\x20\x03(\x0b2\x1c.microchat.fri\
+    end.FriendTeamR\x05teams\x12\x18\n\x07removed\x18\x05\x20\x03(\tR\x07rem\
+    oved\"\x93\x01\n\x15UpdateTeamInfoRequest\x125\n\x04type\x18\x01\x20\x01\
+    (\x0e2!.microchat.msg.GroupOperationTypeR\x04type\x12\x12\n\x04name\x18\
+    \x02\x20\x01(\tR\x04name\x12\x19\n\x08new_name\x18\x03\x20\x01(\tR\x07ne\
+    wName\x12\x14\n\x05names\x18\x04\x20\x03(\tR\x05names\"\xa2\x01\n\x13Upd\
+    ateTeamInfoReply\x12,\n\x04code\x18\x01\x20\x01(\x0e2\x18.microchat.msg.\
+    ErrorCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg\x125\n\
+    \x04type\x18\x03\x20\x01(\x0e2!.microchat.msg.GroupOperationTypeR\x04typ\
+    e\x12\x14\n\x05teams\x18\x04\x20\x03(\tR\x05teamsb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(2);
+            deps.push(super::chatmsg::file_descriptor().clone());
+            deps.push(super::user::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(9);
+            messages.push(FriendApply::generated_message_descriptor_data());
+            messages.push(OperateFriendRequest::generated_message_descriptor_data());
+            messages.push(OperateFriendReply::generated_message_descriptor_data());
+            messages.push(FriendInfo::generated_message_descriptor_data());
+            messages.push(FriendTeam::generated_message_descriptor_data());
+            messages.push(GetFriendListRequest::generated_message_descriptor_data());
+            messages.push(GetFriendListReply::generated_message_descriptor_data());
+            messages.push(UpdateTeamInfoRequest::generated_message_descriptor_data());
+            messages.push(UpdateTeamInfoReply::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -1,4 +1,6 @@
 // @generated
 
+pub mod chat;
 pub mod chatmsg;
+pub mod friend;
 pub mod user;
@@ -814,6 +814,1755 @@ pub mod login_request {
     }
 }
 
+// @@protoc_insertion_point(message:microchat.user.LoginReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LoginReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.LoginReply.code)
+    pub code: ::protobuf::EnumOrUnknown<super::chatmsg::ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.user.LoginReply.msg)
+    pub msg: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.LoginReply.user_id)
+    pub user_id: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.LoginReply.access_token)
+    pub access_token: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.LoginReply.refresh_token)
+    pub refresh_token: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.LoginReply.expires_in)
+    pub expires_in: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.LoginReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LoginReply {
+    fn default() -> &'a LoginReply {
+        <LoginReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LoginReply {
+    pub fn new() -> LoginReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &LoginReply| { &m.code },
+            |m: &mut LoginReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &LoginReply| { &m.msg },
+            |m: &mut LoginReply| { &mut m.msg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "user_id",
+            |m: &LoginReply| { &m.user_id },
+            |m: &mut LoginReply| { &mut m.user_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "access_token",
+            |m: &LoginReply| { &m.access_token },
+            |m: &mut LoginReply| { &mut m.access_token },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "refresh_token",
+            |m: &LoginReply| { &m.refresh_token },
+            |m: &mut LoginReply| { &mut m.refresh_token },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "expires_in",
+            |m: &LoginReply| { &m.expires_in },
+            |m: &mut LoginReply| { &mut m.expires_in },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LoginReply>(
+            "LoginReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LoginReply {
+    const NAME: &'static str = "LoginReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                26 => {
+                    self.user_id = is.read_string()?;
+                },
+                34 => {
+                    self.access_token = is.read_string()?;
+                },
+                42 => {
+                    self.refresh_token = is.read_string()?;
+                },
+                48 => {
+                    self.expires_in = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.user_id);
+        }
+        if !self.access_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.access_token);
+        }
+        if !self.refresh_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.refresh_token);
+        }
+        if self.expires_in != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.expires_in);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(3, &self.user_id)?;
+        }
+        if !self.access_token.is_empty() {
+            os.write_string(4, &self.access_token)?;
+        }
+        if !self.refresh_token.is_empty() {
+            os.write_string(5, &self.refresh_token)?;
+        }
+        if self.expires_in != 0 {
+            os.write_uint64(6, self.expires_in)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LoginReply {
+        LoginReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.user_id.clear();
+        self.access_token.clear();
+        self.refresh_token.clear();
+        self.expires_in = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LoginReply {
+        static instance: LoginReply = LoginReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            user_id: ::std::string::String::new(),
+            access_token: ::std::string::String::new(),
+            refresh_token: ::std::string::String::new(),
+            expires_in: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LoginReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LoginReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LoginReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LoginReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.user.UserProfile)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UserProfile {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.user_id)
+    pub user_id: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.user_name)
+    pub user_name: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.nick_name)
+    pub nick_name: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.gender)
+    pub gender: ::protobuf::EnumOrUnknown<Gender>,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.birthday)
+    pub birthday: i64,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.signature)
+    pub signature: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.address)
+    pub address: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.facetype)
+    pub facetype: i32,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.customface)
+    pub customface: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.UserProfile.customfacefmt)
+    pub customfacefmt: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.UserProfile.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UserProfile {
+    fn default() -> &'a UserProfile {
+        <UserProfile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UserProfile {
+    pub fn new() -> UserProfile {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(10);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "user_id",
+            |m: &UserProfile| { &m.user_id },
+            |m: &mut UserProfile| { &mut m.user_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "user_name",
+            |m: &UserProfile| { &m.user_name },
+            |m: &mut UserProfile| { &mut m.user_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "nick_name",
+            |m: &UserProfile| { &m.nick_name },
+            |m: &mut UserProfile| { &mut m.nick_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "gender",
+            |m: &UserProfile| { &m.gender },
+            |m: &mut UserProfile| { &mut m.gender },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "birthday",
+            |m: &UserProfile| { &m.birthday },
+            |m: &mut UserProfile| { &mut m.birthday },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "signature",
+            |m: &UserProfile| { &m.signature },
+            |m: &mut UserProfile| { &mut m.signature },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "address",
+            |m: &UserProfile| { &m.address },
+            |m: &mut UserProfile| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "facetype",
+            |m: &UserProfile| { &m.facetype },
+            |m: &mut UserProfile| { &mut m.facetype },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "customface",
+            |m: &UserProfile| { &m.customface },
+            |m: &mut UserProfile| { &mut m.customface },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "customfacefmt",
+            |m: &UserProfile| { &m.customfacefmt },
+            |m: &mut UserProfile| { &mut m.customfacefmt },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UserProfile>(
+            "UserProfile",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UserProfile {
+    const NAME: &'static str = "UserProfile";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.user_id = is.read_string()?;
+                },
+                18 => {
+                    self.user_name = is.read_string()?;
+                },
+                26 => {
+                    self.nick_name = is.read_string()?;
+                },
+                32 => {
+                    self.gender = is.read_enum_or_unknown()?;
+                },
+                40 => {
+                    self.birthday = is.read_int64()?;
+                },
+                50 => {
+                    self.signature = is.read_string()?;
+                },
+                58 => {
+                    self.address = is.read_string()?;
+                },
+                64 => {
+                    self.facetype = is.read_int32()?;
+                },
+                74 => {
+                    self.customface = is.read_string()?;
+                },
+                82 => {
+                    self.customfacefmt = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        if !self.user_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_name);
+        }
+        if !self.nick_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.nick_name);
+        }
+        if self.gender != ::protobuf::EnumOrUnknown::new(Gender::Male) {
+            my_size += ::protobuf::rt::int32_size(4, self.gender.value());
+        }
+        if self.birthday != 0 {
+            my_size += ::protobuf::rt::int64_size(5, self.birthday);
+        }
+        if !self.signature.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.signature);
+        }
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.address);
+        }
+        if self.facetype != 0 {
+            my_size += ::protobuf::rt::int32_size(8, self.facetype);
+        }
+        if !self.customface.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.customface);
+        }
+        if !self.customfacefmt.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.customfacefmt);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        if !self.user_name.is_empty() {
+            os.write_string(2, &self.user_name)?;
+        }
+        if !self.nick_name.is_empty() {
+            os.write_string(3, &self.nick_name)?;
+        }
+        if self.gender != ::protobuf::EnumOrUnknown::new(Gender::Male) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.gender))?;
+        }
+        if self.birthday != 0 {
+            os.write_int64(5, self.birthday)?;
+        }
+        if !self.signature.is_empty() {
+            os.write_string(6, &self.signature)?;
+        }
+        if !self.address.is_empty() {
+            os.write_string(7, &self.address)?;
+        }
+        if self.facetype != 0 {
+            os.write_int32(8, self.facetype)?;
+        }
+        if !self.customface.is_empty() {
+            os.write_string(9, &self.customface)?;
+        }
+        if !self.customfacefmt.is_empty() {
+            os.write_string(10, &self.customfacefmt)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UserProfile {
+        UserProfile::new()
+    }
+
+    fn clear(&mut self) {
+        self.user_id.clear();
+        self.user_name.clear();
+        self.nick_name.clear();
+        self.gender = ::protobuf::EnumOrUnknown::new(Gender::Male);
+        self.birthday = 0;
+        self.signature.clear();
+        self.address.clear();
+        self.facetype = 0;
+        self.customface.clear();
+        self.customfacefmt.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UserProfile {
+        static instance: UserProfile = UserProfile {
+            user_id: ::std::string::String::new(),
+            user_name: ::std::string::String::new(),
+            nick_name: ::std::string::String::new(),
+            gender: ::protobuf::EnumOrUnknown::from_i32(0),
+            birthday: 0,
+            signature: ::std::string::String::new(),
+            address: ::std::string::String::new(),
+            facetype: 0,
+            customface: ::std::string::String::new(),
+            customfacefmt: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UserProfile {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UserProfile").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UserProfile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UserProfile {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.user.UserStatusChange)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UserStatusChange {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.UserStatusChange.user_id)
+    pub user_id: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.UserStatusChange.client_type)
+    pub client_type: ::protobuf::EnumOrUnknown<super::chatmsg::ClientType>,
+    // @@protoc_insertion_point(field:microchat.user.UserStatusChange.status)
+    pub status: ::protobuf::EnumOrUnknown<super::chatmsg::OnlineStatus>,
+    // @@protoc_insertion_point(field:microchat.user.UserStatusChange.change_type)
+    pub change_type: ::protobuf::EnumOrUnknown<StatusChangeType>,
+    // @@protoc_insertion_point(field:microchat.user.UserStatusChange.profile)
+    pub profile: ::protobuf::MessageField<UserProfile>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.UserStatusChange.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UserStatusChange {
+    fn default() -> &'a UserStatusChange {
+        <UserStatusChange as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UserStatusChange {
+    pub fn new() -> UserStatusChange {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "user_id",
+            |m: &UserStatusChange| { &m.user_id },
+            |m: &mut UserStatusChange| { &mut m.user_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "client_type",
+            |m: &UserStatusChange| { &m.client_type },
+            |m: &mut UserStatusChange| { &mut m.client_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "status",
+            |m: &UserStatusChange| { &m.status },
+            |m: &mut UserStatusChange| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "change_type",
+            |m: &UserStatusChange| { &m.change_type },
+            |m: &mut UserStatusChange| { &mut m.change_type },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, UserProfile>(
+            "profile",
+            |m: &UserStatusChange| { &m.profile },
+            |m: &mut UserStatusChange| { &mut m.profile },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UserStatusChange>(
+            "UserStatusChange",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UserStatusChange {
+    const NAME: &'static str = "UserStatusChange";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.user_id = is.read_string()?;
+                },
+                16 => {
+                    self.client_type = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.status = is.read_enum_or_unknown()?;
+                },
+                32 => {
+                    self.change_type = is.read_enum_or_unknown()?;
+                },
+                42 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.profile)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        if self.client_type != ::protobuf::EnumOrUnknown::new(super::chatmsg::ClientType::WINDOWS) {
+            my_size += ::protobuf::rt::int32_size(2, self.client_type.value());
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(super::chatmsg::OnlineStatus::OFFLINE) {
+            my_size += ::protobuf::rt::int32_size(3, self.status.value());
+        }
+        if self.change_type != ::protobuf::EnumOrUnknown::new(StatusChangeType::ONLINE_STATUS) {
+            my_size += ::protobuf::rt::int32_size(4, self.change_type.value());
+        }
+        if let Some(v) = self.profile.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        if self.client_type != ::protobuf::EnumOrUnknown::new(super::chatmsg::ClientType::WINDOWS) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.client_type))?;
+        }
+        if self.status != ::protobuf::EnumOrUnknown::new(super::chatmsg::OnlineStatus::OFFLINE) {
+            os.write_enum(3, ::protobuf::EnumOrUnknown::value(&self.status))?;
+        }
+        if self.change_type != ::protobuf::EnumOrUnknown::new(StatusChangeType::ONLINE_STATUS) {
+            os.write_enum(4, ::protobuf::EnumOrUnknown::value(&self.change_type))?;
+        }
+        if let Some(v) = self.profile.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UserStatusChange {
+        UserStatusChange::new()
+    }
+
+    fn clear(&mut self) {
+        self.user_id.clear();
+        self.client_type = ::protobuf::EnumOrUnknown::new(super::chatmsg::ClientType::WINDOWS);
+        self.status = ::protobuf::EnumOrUnknown::new(super::chatmsg::OnlineStatus::OFFLINE);
+        self.change_type = ::protobuf::EnumOrUnknown::new(StatusChangeType::ONLINE_STATUS);
+        self.profile.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UserStatusChange {
+        static instance: UserStatusChange = UserStatusChange {
+            user_id: ::std::string::String::new(),
+            client_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            status: ::protobuf::EnumOrUnknown::from_i32(0),
+            change_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            profile: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UserStatusChange {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UserStatusChange").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UserStatusChange {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UserStatusChange {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.user.ModifyPasswordRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ModifyPasswordRequest {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.ModifyPasswordRequest.old_password)
+    pub old_password: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.ModifyPasswordRequest.new_password)
+    pub new_password: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.ModifyPasswordRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ModifyPasswordRequest {
+    fn default() -> &'a ModifyPasswordRequest {
+        <ModifyPasswordRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ModifyPasswordRequest {
+    pub fn new() -> ModifyPasswordRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "old_password",
+            |m: &ModifyPasswordRequest| { &m.old_password },
+            |m: &mut ModifyPasswordRequest| { &mut m.old_password },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "new_password",
+            |m: &ModifyPasswordRequest| { &m.new_password },
+            |m: &mut ModifyPasswordRequest| { &mut m.new_password },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ModifyPasswordRequest>(
+            "ModifyPasswordRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ModifyPasswordRequest {
+    const NAME: &'static str = "ModifyPasswordRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.old_password = is.read_string()?;
+                },
+                18 => {
+                    self.new_password = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.old_password.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.old_password);
+        }
+        if !self.new_password.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.new_password);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.old_password.is_empty() {
+            os.write_string(1, &self.old_password)?;
+        }
+        if !self.new_password.is_empty() {
+            os.write_string(2, &self.new_password)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ModifyPasswordRequest {
+        ModifyPasswordRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.old_password.clear();
+        self.new_password.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ModifyPasswordRequest {
+        static instance: ModifyPasswordRequest = ModifyPasswordRequest {
+            old_password: ::std::string::String::new(),
+            new_password: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ModifyPasswordRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ModifyPasswordRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ModifyPasswordRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ModifyPasswordRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.user.ModifyPasswordReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ModifyPasswordReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.ModifyPasswordReply.code)
+    pub code: ::protobuf::EnumOrUnknown<super::chatmsg::ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.user.ModifyPasswordReply.msg)
+    pub msg: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.ModifyPasswordReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ModifyPasswordReply {
+    fn default() -> &'a ModifyPasswordReply {
+        <ModifyPasswordReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ModifyPasswordReply {
+    pub fn new() -> ModifyPasswordReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &ModifyPasswordReply| { &m.code },
+            |m: &mut ModifyPasswordReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &ModifyPasswordReply| { &m.msg },
+            |m: &mut ModifyPasswordReply| { &mut m.msg },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ModifyPasswordReply>(
+            "ModifyPasswordReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ModifyPasswordReply {
+    const NAME: &'static str = "ModifyPasswordReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ModifyPasswordReply {
+        ModifyPasswordReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ModifyPasswordReply {
+        static instance: ModifyPasswordReply = ModifyPasswordReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ModifyPasswordReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ModifyPasswordReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ModifyPasswordReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ModifyPasswordReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.user.UpdateUserInfoRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UpdateUserInfoRequest {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.nickname)
+    pub nickname: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.gender)
+    pub gender: ::std::option::Option<::protobuf::EnumOrUnknown<Gender>>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.birthday)
+    pub birthday: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.signature)
+    pub signature: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.address)
+    pub address: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.email)
+    pub email: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.facetype)
+    pub facetype: ::std::option::Option<i32>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.customface)
+    pub customface: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoRequest.phone_searchable)
+    pub phone_searchable: ::std::option::Option<bool>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.UpdateUserInfoRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateUserInfoRequest {
+    fn default() -> &'a UpdateUserInfoRequest {
+        <UpdateUserInfoRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateUserInfoRequest {
+    pub fn new() -> UpdateUserInfoRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(9);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "nickname",
+            |m: &UpdateUserInfoRequest| { &m.nickname },
+            |m: &mut UpdateUserInfoRequest| { &mut m.nickname },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "gender",
+            |m: &UpdateUserInfoRequest| { &m.gender },
+            |m: &mut UpdateUserInfoRequest| { &mut m.gender },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "birthday",
+            |m: &UpdateUserInfoRequest| { &m.birthday },
+            |m: &mut UpdateUserInfoRequest| { &mut m.birthday },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "signature",
+            |m: &UpdateUserInfoRequest| { &m.signature },
+            |m: &mut UpdateUserInfoRequest| { &mut m.signature },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "address",
+            |m: &UpdateUserInfoRequest| { &m.address },
+            |m: &mut UpdateUserInfoRequest| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "email",
+            |m: &UpdateUserInfoRequest| { &m.email },
+            |m: &mut UpdateUserInfoRequest| { &mut m.email },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "facetype",
+            |m: &UpdateUserInfoRequest| { &m.facetype },
+            |m: &mut UpdateUserInfoRequest| { &mut m.facetype },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "customface",
+            |m: &UpdateUserInfoRequest| { &m.customface },
+            |m: &mut UpdateUserInfoRequest| { &mut m.customface },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "phone_searchable",
+            |m: &UpdateUserInfoRequest| { &m.phone_searchable },
+            |m: &mut UpdateUserInfoRequest| { &mut m.phone_searchable },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UpdateUserInfoRequest>(
+            "UpdateUserInfoRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UpdateUserInfoRequest {
+    const NAME: &'static str = "UpdateUserInfoRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.nickname = ::std::option::Option::Some(is.read_string()?);
+                },
+                16 => {
+                    self.gender = ::std::option::Option::Some(is.read_enum_or_unknown()?);
+                },
+                24 => {
+                    self.birthday = ::std::option::Option::Some(is.read_int64()?);
+                },
+                34 => {
+                    self.signature = ::std::option::Option::Some(is.read_string()?);
+                },
+                42 => {
+                    self.address = ::std::option::Option::Some(is.read_string()?);
+                },
+                50 => {
+                    self.email = ::std::option::Option::Some(is.read_string()?);
+                },
+                56 => {
+                    self.facetype = ::std::option::Option::Some(is.read_int32()?);
+                },
+                66 => {
+                    self.customface = ::std::option::Option::Some(is.read_string()?);
+                },
+                72 => {
+                    self.phone_searchable = ::std::option::Option::Some(is.read_bool()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.nickname.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.gender {
+            my_size += ::protobuf::rt::int32_size(2, v.value());
+        }
+        if let Some(v) = self.birthday {
+            my_size += ::protobuf::rt::int64_size(3, v);
+        }
+        if let Some(v) = self.signature.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.address.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(v) = self.email.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(v) = self.facetype {
+            my_size += ::protobuf::rt::int32_size(7, v);
+        }
+        if let Some(v) = self.customface.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        if let Some(v) = self.phone_searchable {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.nickname.as_ref() {
+            os.write_string(1, v)?;
+        }
+        if let Some(v) = self.gender {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&v))?;
+        }
+        if let Some(v) = self.birthday {
+            os.write_int64(3, v)?;
+        }
+        if let Some(v) = self.signature.as_ref() {
+            os.write_string(4, v)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            os.write_string(5, v)?;
+        }
+        if let Some(v) = self.email.as_ref() {
+            os.write_string(6, v)?;
+        }
+        if let Some(v) = self.facetype {
+            os.write_int32(7, v)?;
+        }
+        if let Some(v) = self.customface.as_ref() {
+            os.write_string(8, v)?;
+        }
+        if let Some(v) = self.phone_searchable {
+            os.write_bool(9, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UpdateUserInfoRequest {
+        UpdateUserInfoRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.nickname = ::std::option::Option::None;
+        self.gender = ::std::option::Option::None;
+        self.birthday = ::std::option::Option::None;
+        self.signature = ::std::option::Option::None;
+        self.address = ::std::option::Option::None;
+        self.email = ::std::option::Option::None;
+        self.facetype = ::std::option::Option::None;
+        self.customface = ::std::option::Option::None;
+        self.phone_searchable = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UpdateUserInfoRequest {
+        static instance: UpdateUserInfoRequest = UpdateUserInfoRequest {
+            nickname: ::std::option::Option::None,
+            gender: ::std::option::Option::None,
+            birthday: ::std::option::Option::None,
+            signature: ::std::option::Option::None,
+            address: ::std::option::Option::None,
+            email: ::std::option::Option::None,
+            facetype: ::std::option::Option::None,
+            customface: ::std::option::Option::None,
+            phone_searchable: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UpdateUserInfoRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UpdateUserInfoRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UpdateUserInfoRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateUserInfoRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.user.UpdateUserInfoReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UpdateUserInfoReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoReply.code)
+    pub code: ::protobuf::EnumOrUnknown<super::chatmsg::ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoReply.msg)
+    pub msg: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.UpdateUserInfoReply.profile)
+    pub profile: ::protobuf::MessageField<UserProfile>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.UpdateUserInfoReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UpdateUserInfoReply {
+    fn default() -> &'a UpdateUserInfoReply {
+        <UpdateUserInfoReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UpdateUserInfoReply {
+    pub fn new() -> UpdateUserInfoReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &UpdateUserInfoReply| { &m.code },
+            |m: &mut UpdateUserInfoReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &UpdateUserInfoReply| { &m.msg },
+            |m: &mut UpdateUserInfoReply| { &mut m.msg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, UserProfile>(
+            "profile",
+            |m: &UpdateUserInfoReply| { &m.profile },
+            |m: &mut UpdateUserInfoReply| { &mut m.profile },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UpdateUserInfoReply>(
+            "UpdateUserInfoReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UpdateUserInfoReply {
+    const NAME: &'static str = "UpdateUserInfoReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.profile)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if let Some(v) = self.profile.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if let Some(v) = self.profile.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UpdateUserInfoReply {
+        UpdateUserInfoReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.profile.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UpdateUserInfoReply {
+        static instance: UpdateUserInfoReply = UpdateUserInfoReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            profile: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UpdateUserInfoReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UpdateUserInfoReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UpdateUserInfoReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UpdateUserInfoReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.user.FindUserRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct FindUserRequest {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.FindUserRequest.type)
+    pub type_: ::protobuf::EnumOrUnknown<FindUserType>,
+    // @@protoc_insertion_point(field:microchat.user.FindUserRequest.keyword)
+    pub keyword: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.FindUserRequest.page)
+    pub page: u32,
+    // @@protoc_insertion_point(field:microchat.user.FindUserRequest.page_size)
+    pub page_size: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.FindUserRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FindUserRequest {
+    fn default() -> &'a FindUserRequest {
+        <FindUserRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FindUserRequest {
+    pub fn new() -> FindUserRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "type",
+            |m: &FindUserRequest| { &m.type_ },
+            |m: &mut FindUserRequest| { &mut m.type_ },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "keyword",
+            |m: &FindUserRequest| { &m.keyword },
+            |m: &mut FindUserRequest| { &mut m.keyword },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "page",
+            |m: &FindUserRequest| { &m.page },
+            |m: &mut FindUserRequest| { &mut m.page },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "page_size",
+            |m: &FindUserRequest| { &m.page_size },
+            |m: &mut FindUserRequest| { &mut m.page_size },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FindUserRequest>(
+            "FindUserRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FindUserRequest {
+    const NAME: &'static str = "FindUserRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.type_ = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.keyword = is.read_string()?;
+                },
+                24 => {
+                    self.page = is.read_uint32()?;
+                },
+                32 => {
+                    self.page_size = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.type_ != ::protobuf::EnumOrUnknown::new(FindUserType::FIND_BY_USER_ID) {
+            my_size += ::protobuf::rt::int32_size(1, self.type_.value());
+        }
+        if !self.keyword.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.keyword);
+        }
+        if self.page != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.page);
+        }
+        if self.page_size != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.page_size);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.type_ != ::protobuf::EnumOrUnknown::new(FindUserType::FIND_BY_USER_ID) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.type_))?;
+        }
+        if !self.keyword.is_empty() {
+            os.write_string(2, &self.keyword)?;
+        }
+        if self.page != 0 {
+            os.write_uint32(3, self.page)?;
+        }
+        if self.page_size != 0 {
+            os.write_uint32(4, self.page_size)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FindUserRequest {
+        FindUserRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.type_ = ::protobuf::EnumOrUnknown::new(FindUserType::FIND_BY_USER_ID);
+        self.keyword.clear();
+        self.page = 0;
+        self.page_size = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FindUserRequest {
+        static instance: FindUserRequest = FindUserRequest {
+            type_: ::protobuf::EnumOrUnknown::from_i32(0),
+            keyword: ::std::string::String::new(),
+            page: 0,
+            page_size: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FindUserRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FindUserRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FindUserRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindUserRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:microchat.user.FindUserReply)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct FindUserReply {
+    // message fields
+    // @@protoc_insertion_point(field:microchat.user.FindUserReply.code)
+    pub code: ::protobuf::EnumOrUnknown<super::chatmsg::ErrorCode>,
+    // @@protoc_insertion_point(field:microchat.user.FindUserReply.msg)
+    pub msg: ::std::string::String,
+    // @@protoc_insertion_point(field:microchat.user.FindUserReply.total)
+    pub total: u64,
+    // @@protoc_insertion_point(field:microchat.user.FindUserReply.users)
+    pub users: ::std::vec::Vec<UserProfile>,
+    // special fields
+    // @@protoc_insertion_point(special_field:microchat.user.FindUserReply.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a FindUserReply {
+    fn default() -> &'a FindUserReply {
+        <FindUserReply as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FindUserReply {
+    pub fn new() -> FindUserReply {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &FindUserReply| { &m.code },
+            |m: &mut FindUserReply| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "msg",
+            |m: &FindUserReply| { &m.msg },
+            |m: &mut FindUserReply| { &mut m.msg },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total",
+            |m: &FindUserReply| { &m.total },
+            |m: &mut FindUserReply| { &mut m.total },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "users",
+            |m: &FindUserReply| { &m.users },
+            |m: &mut FindUserReply| { &mut m.users },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FindUserReply>(
+            "FindUserReply",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for FindUserReply {
+    const NAME: &'static str = "FindUserReply";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                18 => {
+                    self.msg = is.read_string()?;
+                },
+                24 => {
+                    self.total = is.read_uint64()?;
+                },
+                34 => {
+                    self.users.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(1, self.code.value());
+        }
+        if !self.msg.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.msg);
+        }
+        if self.total != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.total);
+        }
+        for value in &self.users {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if !self.msg.is_empty() {
+            os.write_string(2, &self.msg)?;
+        }
+        if self.total != 0 {
+            os.write_uint64(3, self.total)?;
+        }
+        for v in &self.users {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> FindUserReply {
+        FindUserReply::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = ::protobuf::EnumOrUnknown::new(super::chatmsg::ErrorCode::ERR_UNKNOWN);
+        self.msg.clear();
+        self.total = 0;
+        self.users.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static FindUserReply {
+        static instance: FindUserReply = FindUserReply {
+            code: ::protobuf::EnumOrUnknown::from_i32(0),
+            msg: ::std::string::String::new(),
+            total: 0,
+            users: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for FindUserReply {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("FindUserReply").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for FindUserReply {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FindUserReply {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
 #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
 // @@protoc_insertion_point(enum:microchat.user.Gender)
 pub enum Gender {
@@ -881,6 +2630,140 @@ impl Gender {
     }
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:microchat.user.StatusChangeType)
+pub enum StatusChangeType {
+    // @@protoc_insertion_point(enum_value:microchat.user.StatusChangeType.ONLINE_STATUS)
+    ONLINE_STATUS = 0,
+    // @@protoc_insertion_point(enum_value:microchat.user.StatusChangeType.PROFILE)
+    PROFILE = 1,
+}
+
+impl ::protobuf::Enum for StatusChangeType {
+    const NAME: &'static str = "StatusChangeType";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<StatusChangeType> {
+        match value {
+            0 => ::std::option::Option::Some(StatusChangeType::ONLINE_STATUS),
+            1 => ::std::option::Option::Some(StatusChangeType::PROFILE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<StatusChangeType> {
+        match str {
+            "ONLINE_STATUS" => ::std::option::Option::Some(StatusChangeType::ONLINE_STATUS),
+            "PROFILE" => ::std::option::Option::Some(StatusChangeType::PROFILE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [StatusChangeType] = &[
+        StatusChangeType::ONLINE_STATUS,
+        StatusChangeType::PROFILE,
+    ];
+}
+
+impl ::protobuf::EnumFull for StatusChangeType {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("StatusChangeType").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for StatusChangeType {
+    fn default() -> Self {
+        StatusChangeType::ONLINE_STATUS
+    }
+}
+
+impl StatusChangeType {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<StatusChangeType>("StatusChangeType")
+    }
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:microchat.user.FindUserType)
+pub enum FindUserType {
+    // @@protoc_insertion_point(enum_value:microchat.user.FindUserType.FIND_BY_USER_ID)
+    FIND_BY_USER_ID = 0,
+    // @@protoc_insertion_point(enum_value:microchat.user.FindUserType.FIND_BY_USERNAME)
+    FIND_BY_USERNAME = 1,
+    // @@protoc_insertion_point(enum_value:microchat.user.FindUserType.FIND_BY_PHONE)
+    FIND_BY_PHONE = 2,
+    // @@protoc_insertion_point(enum_value:microchat.user.FindUserType.FIND_BY_NICKNAME)
+    FIND_BY_NICKNAME = 3,
+}
+
+impl ::protobuf::Enum for FindUserType {
+    const NAME: &'static str = "FindUserType";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<FindUserType> {
+        match value {
+            0 => ::std::option::Option::Some(FindUserType::FIND_BY_USER_ID),
+            1 => ::std::option::Option::Some(FindUserType::FIND_BY_USERNAME),
+            2 => ::std::option::Option::Some(FindUserType::FIND_BY_PHONE),
+            3 => ::std::option::Option::Some(FindUserType::FIND_BY_NICKNAME),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<FindUserType> {
+        match str {
+            "FIND_BY_USER_ID" => ::std::option::Option::Some(FindUserType::FIND_BY_USER_ID),
+            "FIND_BY_USERNAME" => ::std::option::Option::Some(FindUserType::FIND_BY_USERNAME),
+            "FIND_BY_PHONE" => ::std::option::Option::Some(FindUserType::FIND_BY_PHONE),
+            "FIND_BY_NICKNAME" => ::std::option::Option::Some(FindUserType::FIND_BY_NICKNAME),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [FindUserType] = &[
+        FindUserType::FIND_BY_USER_ID,
+        FindUserType::FIND_BY_USERNAME,
+        FindUserType::FIND_BY_PHONE,
+        FindUserType::FIND_BY_NICKNAME,
+    ];
+}
+
+impl ::protobuf::EnumFull for FindUserType {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("FindUserType").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for FindUserType {
+    fn default() -> Self {
+        FindUserType::FIND_BY_USER_ID
+    }
+}
+
+impl FindUserType {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<FindUserType>("FindUserType")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\nuser.proto\x12\x0emicrochat.user\x1a\rchatmsg.proto\"\xad\x01\n\x0fR\
     egisterRequest\x12\x1a\n\x08username\x18\x01\x20\x01(\tR\x08username\x12\
@@ -895,9 +2778,59 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     H\0R\x06custom\x125\n\x06mobile\x18\x02\x20\x01(\x0b2\x1b.microchat.user\
     .MobileLoginH\0R\x06mobile\x12:\n\x0bclient_type\x18\x03\x20\x01(\x0e2\
     \x19.microchat.msg.ClientTypeR\nclientType\x123\n\x06status\x18\x04\x20\
-    \x01(\x0e2\x1b.microchat.msg.OnlineStatusR\x06statusB\x0c\n\nlogin_type*\
-    +\n\x06Gender\x12\x08\n\x04Male\x10\0\x12\n\n\x06Female\x10\x01\x12\x0b\
-    \n\x07Unknown\x10\x02b\x06proto3\
+    \x01(\x0e2\x1b.microchat.msg.OnlineStatusR\x06statusB\x0c\n\nlogin_type\
+    \"\xcc\x01\n\nLoginReply\x12,\n\x04code\x18\x01\x20\x01(\x0e2\x18.microc\
+    hat.msg.ErrorCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg\
+    \x12\x17\n\x07user_id\x18\x03\x20\x01(\tR\x06userId\x12!\n\x0caccess_tok\
+    en\x18\x04\x20\x01(\tR\x0baccessToken\x12#\n\rrefresh_token\x18\x05\x20\
+    \x01(\tR\x0crefreshToken\x12\x1d\n\nexpires_in\x18\x06\x20\x01(\x04R\tex\
+    piresIn\"\xc6\x02\n\x0bUserProfile\x12\x17\n\x07user_id\x18\x01\x20\x01(\
+    \tR\x06userId\x12\x1b\n\tuser_name\x18\x02\x20\x01(\tR\x08userName\x12\
+    \x1b\n\tnick_name\x18\x03\x20\x01(\tR\x08nickName\x12.\n\x06gender\x18\
+    \x04\x20\x01(\x0e2\x16.microchat.user.GenderR\x06gender\x12\x1a\n\x08bir\
+    thday\x18\x05\x20\x01(\x03R\x08birthday\x12\x1c\n\tsignature\x18\x06\x20\
+    \x01(\tR\tsignature\x12\x18\n\x07address\x18\x07\x20\x01(\tR\x07address\
+    \x12\x1a\n\x08facetype\x18\x08\x20\x01(\x05R\x08facetype\x12\x1e\n\ncust\
+    omface\x18\t\x20\x01(\tR\ncustomface\x12$\n\rcustomfacefmt\x18\n\x20\x01\
+    (\tR\rcustomfacefmt\"\x96\x02\n\x10UserStatusChange\x12\x17\n\x07user_id\
+    \x18\x01\x20\x01(\tR\x06userId\x12:\n\x0bclient_type\x18\x02\x20\x01(\
+    \x0e2\x19.microchat.msg.ClientTypeR\nclientType\x123\n\x06status\x18\x03\
+    \x20\x01(\x0e2\x1b.microchat.msg.OnlineStatusR\x06status\x12A\n\x0bchang\
+    e_type\x18\x04\x20\x01(\x0e2\x20.microchat.user.StatusChangeTypeR\nchang\
+    eType\x125\n\x07profile\x18\x05\x20\x01(\x0b2\x1b.microchat.user.UserPro\
+    fileR\x07profile\"]\n\x15ModifyPasswordRequest\x12!\n\x0cold_password\
+    \x18\x01\x20\x01(\tR\x0boldPassword\x12!\n\x0cnew_password\x18\x02\x20\
+    \x01(\tR\x0bnewPassword\"U\n\x13ModifyPasswordReply\x12,\n\x04code\x18\
+    \x01\x20\x01(\x0e2\x18.microchat.msg.ErrorCodeR\x04code\x12\x10\n\x03msg\
+    \x18\x02\x20\x01(\tR\x03msg\"\xdb\x03\n\x15UpdateUserInfoRequest\x12\x1f\
+    \n\x08nickname\x18\x01\x20\x01(\tH\0R\x08nickname\x88\x01\x01\x123\n\x06\
+    gender\x18\x02\x20\x01(\x0e2\x16.microchat.user.GenderH\x01R\x06gender\
+    \x88\x01\x01\x12\x1f\n\x08birthday\x18\x03\x20\x01(\x03H\x02R\x08birthda\
+    y\x88\x01\x01\x12!\n\tsignature\x18\x04\x20\x01(\tH\x03R\tsignature\x88\
+    \x01\x01\x12\x1d\n\x07address\x18\x05\x20\x01(\tH\x04R\x07address\x88\
+    \x01\x01\x12\x19\n\x05email\x18\x06\x20\x01(\tH\x05R\x05email\x88\x01\
+    \x01\x12\x1f\n\x08facetype\x18\x07\x20\x01(\x05H\x06R\x08facetype\x88\
+    \x01\x01\x12#\n\ncustomface\x18\x08\x20\x01(\tH\x07R\ncustomface\x88\x01\
+    \x01\x12.\n\x10phone_searchable\x18\t\x20\x01(\x08H\x08R\x0fphoneSearcha\
+    ble\x88\x01\x01B\x0b\n\t_nicknameB\t\n\x07_genderB\x0b\n\t_birthdayB\x0c\
+    \n\n_signatureB\n\n\x08_addressB\x08\n\x06_emailB\x0b\n\t_facetypeB\r\n\
+    \x0b_customfaceB\x13\n\x11_phone_searchable\"\x8c\x01\n\x13UpdateUserInf\
+    oReply\x12,\n\x04code\x18\x01\x20\x01(\x0e2\x18.microchat.msg.ErrorCodeR\
+    \x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg\x125\n\x07profile\
+    \x18\x03\x20\x01(\x0b2\x1b.microchat.user.UserProfileR\x07profile\"\x8e\
+    \x01\n\x0fFindUserRequest\x120\n\x04type\x18\x01\x20\x01(\x0e2\x1c.micro\
+    chat.user.FindUserTypeR\x04type\x12\x18\n\x07keyword\x18\x02\x20\x01(\tR\
+    \x07keyword\x12\x12\n\x04page\x18\x03\x20\x01(\rR\x04page\x12\x1b\n\tpag\
+    e_size\x18\x04\x20\x01(\rR\x08pageSize\"\x98\x01\n\rFindUserReply\x12,\n\
+    \x04code\x18\x01\x20\x01(\x0e2\x18.microchat.msg.ErrorCodeR\x04code\x12\
+    \x10\n\x03msg\x18\x02\x20\x01(\tR\x03msg\x12\x14\n\x05total\x18\x03\x20\
+    \x01(\x04R\x05total\x121\n\x05users\x18\x04\x20\x03(\x0b2\x1b.microchat.\
+    user.UserProfileR\x05users*+\n\x06Gender\x12\x08\n\x04Male\x10\0\x12\n\n\
+    \x06Female\x10\x01\x12\x0b\n\x07Unknown\x10\x02*2\n\x10StatusChangeType\
+    \x12\x11\n\rONLINE_STATUS\x10\0\x12\x0b\n\x07PROFILE\x10\x01*b\n\x0cFind\
+    UserType\x12\x13\n\x0fFIND_BY_USER_ID\x10\0\x12\x14\n\x10FIND_BY_USERNAM\
+    E\x10\x01\x12\x11\n\rFIND_BY_PHONE\x10\x02\x12\x14\n\x10FIND_BY_NICKNAME\
+    \x10\x03b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -916,13 +2849,24 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(1);
             deps.push(super::chatmsg::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(4);
+            let mut messages = ::std::vec::Vec::with_capacity(13);
             messages.push(RegisterRequest::generated_message_descriptor_data());
             messages.push(CustomLogin::generated_message_descriptor_data());
             messages.push(MobileLogin::generated_message_descriptor_data());
             messages.push(LoginRequest::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(1);
+            messages.push(LoginReply::generated_message_descriptor_data());
+            messages.push(UserProfile::generated_message_descriptor_data());
+            messages.push(UserStatusChange::generated_message_descriptor_data());
+            messages.push(ModifyPasswordRequest::generated_message_descriptor_data());
+            messages.push(ModifyPasswordReply::generated_message_descriptor_data());
+            messages.push(UpdateUserInfoRequest::generated_message_descriptor_data());
+            messages.push(UpdateUserInfoReply::generated_message_descriptor_data());
+            messages.push(FindUserRequest::generated_message_descriptor_data());
+            messages.push(FindUserReply::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(3);
             enums.push(Gender::generated_enum_descriptor_data());
+            enums.push(StatusChangeType::generated_enum_descriptor_data());
+            enums.push(FindUserType::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
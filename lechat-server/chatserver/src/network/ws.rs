@@ -0,0 +1,152 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use actix::prelude::*;
+use actix_web::web::ServiceConfig;
+use actix_web::{get, web, HttpRequest, HttpResponse};
+use actix_web_actors::ws;
+use bytes::BytesMut;
+use shaku::HasComponent;
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::components::Modules;
+use crate::network::codec::PacketCodec;
+use crate::network::dispatcher::ICommandDispatcher;
+use crate::network::packet::Packet;
+use crate::network::session::{self, ISessionManager, Outbound, Session};
+use crate::service;
+
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(index);
+}
+
+/// WebSocket接入, 每个二进制消息携带一个与TCP网关相同格式的数据帧
+#[get("/ws")]
+async fn index(
+    req: HttpRequest,
+    stream: web::Payload,
+    codec: web::Data<PacketCodec>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let modules = service::service_factory()?;
+    let peer = req.peer_addr().unwrap_or_else(|| SocketAddr::from(([0, 0, 0, 0], 0)));
+    let codec = codec.get_ref().clone();
+    let frame_size = codec.max_frame_size();
+    ws::WsResponseBuilder::new(WsSession::new(modules, codec, peer), &req, stream)
+        .frame_size(frame_size)
+        .start()
+}
+
+/// WebSocket连接对应的actor, 会话及分发逻辑与TCP网关共用
+struct WsSession {
+    modules: Arc<Modules>,
+    codec: PacketCodec,
+    peer: SocketAddr,
+    session: Option<Arc<Session>>,
+}
+
+impl WsSession {
+    fn new(modules: Arc<Modules>, codec: PacketCodec, peer: SocketAddr) -> Self {
+        WsSession {
+            modules,
+            codec,
+            peer,
+            session: None,
+        }
+    }
+
+    fn write(&mut self, ctx: &mut ws::WebsocketContext<Self>, packet: Packet) {
+        let mut buf = BytesMut::new();
+        match self.codec.encode(packet, &mut buf) {
+            Ok(_) => ctx.binary(buf.freeze()),
+            Err(err) => tracing::error!("encode packet for {} failed, {err:#}", self.peer),
+        }
+    }
+}
+
+impl Actor for WsSession {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        let session_manager: &dyn ISessionManager = self.modules.resolve_ref();
+        let (session, mut rx) = session_manager.create(self.peer);
+        ctx.add_stream(futures::stream::poll_fn(move |cx| rx.poll_recv(cx)));
+        self.session = Some(session);
+    }
+
+    fn stopped(&mut self, _: &mut Self::Context) {
+        if let Some(session) = self.session.take() {
+            let modules = self.modules.clone();
+            actix::spawn(async move {
+                session::close_session(&modules, &session).await;
+            });
+        }
+        tracing::info!("websocket connection {} closed", self.peer);
+    }
+}
+
+impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WsSession {
+    fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        let msg = match msg {
+            Ok(msg) => msg,
+            Err(err) => {
+                tracing::warn!("websocket connection {} protocol error, {err:#}", self.peer);
+                ctx.stop();
+                return;
+            }
+        };
+
+        match msg {
+            ws::Message::Binary(data) => {
+                let mut buf = BytesMut::from(data.as_ref());
+                let packet = match self.codec.decode(&mut buf) {
+                    Ok(Some(packet)) if buf.is_empty() => packet,
+                    Ok(_) => {
+                        tracing::warn!("websocket message from {} is not a single frame", self.peer);
+                        ctx.stop();
+                        return;
+                    }
+                    Err(err) => {
+                        tracing::warn!("decode websocket frame from {} failed, {err:#}", self.peer);
+                        ctx.stop();
+                        return;
+                    }
+                };
+                let Some(session) = self.session.clone() else {
+                    return;
+                };
+                // 按顺序处理请求, 与TCP网关保持一致
+                let dispatcher: Arc<dyn ICommandDispatcher> = self.modules.resolve();
+                let fut = async move { dispatcher.dispatch(&session, packet).await };
+                ctx.wait(fut.into_actor(self).map(|reply, act, ctx| {
+                    if let Some(reply) = reply {
+                        act.write(ctx, reply);
+                    }
+                }));
+            }
+            ws::Message::Ping(msg) => ctx.pong(&msg),
+            ws::Message::Close(reason) => {
+                ctx.close(reason);
+                ctx.stop();
+            }
+            ws::Message::Text(_) => {
+                tracing::warn!(
+                    "websocket connection {} sent text message, only binary is supported",
+                    self.peer
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+impl StreamHandler<Outbound> for WsSession {
+    fn handle(&mut self, out: Outbound, ctx: &mut Self::Context) {
+        match out {
+            Outbound::Packet(packet) => self.write(ctx, packet),
+            Outbound::Close => {
+                ctx.close(None);
+                ctx.stop();
+            }
+        }
+    }
+}
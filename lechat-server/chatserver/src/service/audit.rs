@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::ActiveValue::Set;
+use sea_orm::NotSet;
+use shaku::{Component, Interface};
+
+use crate::db::entity::user_audit;
+use crate::db::repository::audit::IAuditRepository;
+use crate::service::user::ClientType;
+
+/// 需要审计的安全操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    ModifyPassword,
+    ResetPassword,
+}
+
+impl AuditAction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::ModifyPassword => "modify_password",
+            AuditAction::ResetPassword => "reset_password",
+        }
+    }
+}
+
+/// 操作的来源
+#[derive(Debug, Clone, Default)]
+pub struct AuditSource {
+    pub client_type: Option<ClientType>,
+    pub ip: Option<String>,
+}
+
+#[async_trait]
+pub trait IAuditService: Interface {
+    /// 记录审计日志, 写入失败只记录错误日志, 不影响业务
+    async fn record(&self, user_id: i64, action: AuditAction, source: AuditSource);
+}
+
+#[derive(Component)]
+#[shaku(interface = IAuditService)]
+pub struct AuditServiceImpl {
+    #[shaku(inject)]
+    repo: Arc<dyn IAuditRepository>,
+}
+
+#[async_trait]
+impl IAuditService for AuditServiceImpl {
+    async fn record(&self, user_id: i64, action: AuditAction, source: AuditSource) {
+        tracing::info!("audit: user {} {} from {:?}", user_id, action.as_str(), source);
+        let audit = user_audit::ActiveModel {
+            id: NotSet,
+            user_id: Set(user_id),
+            action: Set(action.as_str().to_string()),
+            client_type: Set(source.client_type.map(|v| format!("{v:?}").to_lowercase())),
+            ip: Set(source.ip),
+            create_time: NotSet,
+        };
+        if let Err(err) = self.repo.add(audit).await {
+            tracing::error!(
+                "record audit {} of user {} failed, {err:#}",
+                action.as_str(),
+                user_id
+            );
+        }
+    }
+}
@@ -0,0 +1,501 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use protobuf::{Enum, MessageField};
+use sea_orm::ActiveValue::Set;
+use serde::{Deserialize, Serialize};
+use shaku::{Component, Interface};
+use validator::Validate;
+
+use crate::base::config::{Config, StrangerChatPolicy};
+use crate::base::response::{Error, Result};
+use crate::db::entity::sea_orm_active_enums::UserStatus;
+use crate::db::entity::{friend_apply, user};
+use crate::db::repository::friend_apply::{IFriendApplyRepository, STATUS_PENDING};
+use crate::db::repository::friend_team::IFriendTeamRepository;
+use crate::db::repository::relationship::{IRelationshipRepository, DEFAULT_GROUP_NAME};
+use crate::db::repository::user::IUserRepository;
+use crate::network::delivery::IDeliveryService;
+use crate::network::packet::Packet;
+use crate::network::registry::ISessionRegistry;
+use crate::network::stubs::chatmsg::{
+    self, ErrorCode, FriendOperationApplyType, FriendOperationType, MsgType,
+};
+use crate::network::stubs::friend::OperateFriendReply;
+use crate::service::user::{OnlineStatus, PublicProfile};
+
+/// 申请列表最多返回的条数
+const APPLY_LIST_LIMIT: u64 = 100;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct FriendApplyRequest {
+    #[validate(length(min = 1, message = "请选择要添加的用户"))]
+    pub user_id: String,
+    #[validate(length(max = 128, message = "验证消息最多128个字符"))]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplyStatus {
+    Pending,
+    Refused,
+    Accepted,
+}
+
+impl From<FriendOperationApplyType> for ApplyStatus {
+    fn from(value: FriendOperationApplyType) -> Self {
+        match value {
+            FriendOperationApplyType::APPLY_UNKNOWN => ApplyStatus::Pending,
+            FriendOperationApplyType::APPLY_REFUSED => ApplyStatus::Refused,
+            FriendOperationApplyType::APPLY_ACCEPTED => ApplyStatus::Accepted,
+        }
+    }
+}
+
+impl From<ApplyStatus> for FriendOperationApplyType {
+    fn from(value: ApplyStatus) -> Self {
+        match value {
+            ApplyStatus::Pending => FriendOperationApplyType::APPLY_UNKNOWN,
+            ApplyStatus::Refused => FriendOperationApplyType::APPLY_REFUSED,
+            ApplyStatus::Accepted => FriendOperationApplyType::APPLY_ACCEPTED,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FriendApplyInfo {
+    pub apply_id: i64,
+    /// 申请人
+    pub from: PublicProfile,
+    /// 被申请人
+    pub to: PublicProfile,
+    pub message: Option<String>,
+    pub status: ApplyStatus,
+    pub create_time: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FriendListRequest {
+    /// 上次同步返回的version, 不传时全量同步
+    pub since: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FriendInfo {
+    pub profile: PublicProfile,
+    /// 备注名称
+    pub mark_name: Option<String>,
+    /// 隐身的好友显示为离线
+    pub status: OnlineStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FriendTeam {
+    pub name: String,
+    pub friends: Vec<FriendInfo>,
+}
+
+/// 好友列表, 增量同步时只包含有变化的好友, 客户端按user_id覆盖本地记录
+#[derive(Debug, Serialize)]
+pub struct FriendList {
+    /// 下次增量同步时作为since传入
+    pub version: i64,
+    pub teams: Vec<FriendTeam>,
+    /// 增量同步时已解除好友关系的user_id
+    pub removed: Vec<String>,
+}
+
+#[async_trait]
+pub trait IFriendService: Interface {
+    /// 发送好友申请, 对方在线时实时推送, 离线时上线后收到
+    async fn apply(&self, user_id: &str, apply_req: FriendApplyRequest) -> Result<FriendApplyInfo>;
+    /// 用户收到及发出的好友申请, 按申请时间倒序
+    async fn list_applies(&self, user_id: &str) -> Result<Vec<FriendApplyInfo>>;
+    /// 接受或拒绝收到的好友申请, 接受后双方成为好友, 结果推送给申请人
+    async fn reply_apply(
+        &self,
+        user_id: &str,
+        apply_id: i64,
+        answer: FriendOperationApplyType,
+    ) -> Result<FriendApplyInfo>;
+    /// 解除好友关系, 通知对方, 对方离线时上线后收到. 返回对方的公开资料
+    async fn delete_friend(&self, user_id: &str, friend_user_id: &str) -> Result<PublicProfile>;
+    /// 按非好友单聊策略检查是否投递单聊消息, 策略为拒绝时返回`Error::NotFriend`
+    async fn can_chat(&self, sender_id: &str, target_id: &str) -> Result<bool>;
+    /// 按分组返回好友列表, `since`为上次同步的version时只返回之后有变化的好友
+    async fn list_friends(&self, user_id: &str, since: Option<i64>) -> Result<FriendList>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IFriendService)]
+pub struct FriendServiceImpl {
+    #[shaku(no_default)]
+    cfg: Arc<Config>,
+    #[shaku(inject)]
+    user_repo: Arc<dyn IUserRepository>,
+    #[shaku(inject)]
+    relationship_repo: Arc<dyn IRelationshipRepository>,
+    #[shaku(inject)]
+    apply_repo: Arc<dyn IFriendApplyRepository>,
+    #[shaku(inject)]
+    team_repo: Arc<dyn IFriendTeamRepository>,
+    #[shaku(inject)]
+    delivery: Arc<dyn IDeliveryService>,
+    #[shaku(inject)]
+    session_registry: Arc<dyn ISessionRegistry>,
+}
+
+impl FriendServiceImpl {
+    async fn find_user(&self, user_id: &str) -> Result<user::Model> {
+        let user = self.user_repo.find_by_user_id(user_id).await.map_err(|err| {
+            tracing::error!("load user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+        match user {
+            Some(u) if u.status == UserStatus::Active => Ok(u),
+            _ => Err(Error::UserNotRegistered),
+        }
+    }
+
+    /// 好友当前的在线状态, 隐身视为离线, 多个设备在线时优先WIFI
+    async fn online_status(&self, user_id: &str) -> chatmsg::OnlineStatus {
+        let Ok(status) = self.session_registry.online_status(user_id).await else {
+            return chatmsg::OnlineStatus::OFFLINE;
+        };
+        status
+            .into_iter()
+            .map(|(_, s)| s)
+            .filter(|s| {
+                !matches!(
+                    s,
+                    chatmsg::OnlineStatus::OFFLINE | chatmsg::OnlineStatus::INVISIBLE
+                )
+            })
+            .min_by_key(|s| s.value())
+            .unwrap_or(chatmsg::OnlineStatus::OFFLINE)
+    }
+
+    /// 推送好友操作给对方, 对方离线时上线后收到
+    async fn push(&self, user_id: &str, mut notify: OperateFriendReply) {
+        notify.code = ErrorCode::ERR_OK.into();
+        let res = match Packet::from_message(MsgType::OPERATE_FRIEND, &notify) {
+            Ok(packet) => self.delivery.deliver(user_id, packet).await,
+            Err(err) => {
+                tracing::error!("encode friend operation failed, {err:#}");
+                Err(Error::InternalServerError)
+            }
+        };
+        if let Err(err) = res {
+            tracing::error!("push friend operation to user {} failed, {err:#}", user_id);
+        }
+    }
+}
+
+#[async_trait]
+impl IFriendService for FriendServiceImpl {
+    async fn apply(&self, user_id: &str, apply_req: FriendApplyRequest) -> Result<FriendApplyInfo> {
+        if apply_req.user_id == user_id {
+            return Err(Error::ParamInvalid("不能添加自己为好友".to_string()));
+        }
+        let me = self.find_user(user_id).await?;
+        let target = self.find_user(&apply_req.user_id).await?;
+        let res = async {
+            if self.relationship_repo.exists(me.id, target.id).await? {
+                return Ok(None);
+            }
+            // 重复申请时只刷新验证消息及申请时间
+            let apply = match self.apply_repo.find_pending(me.id, target.id).await? {
+                Some(apply) => {
+                    let mut model: friend_apply::ActiveModel = apply.into();
+                    model.message = Set(apply_req.message);
+                    model.create_time = Set(Utc::now());
+                    self.apply_repo.update(model).await?
+                }
+                None => {
+                    let model = friend_apply::ActiveModel {
+                        from_user_id: Set(me.id),
+                        to_user_id: Set(target.id),
+                        message: Set(apply_req.message),
+                        status: Set(STATUS_PENDING),
+                        ..Default::default()
+                    };
+                    self.apply_repo.add(model).await?
+                }
+            };
+            Ok(Some(apply))
+        }
+        .await;
+        let apply = res
+            .map_err(|err: sea_orm::DbErr| {
+                tracing::error!(
+                    "apply friend from {} to {} failed, {err:#}",
+                    me.user_id,
+                    target.user_id
+                );
+                Error::InternalServerError
+            })?
+            .ok_or(Error::AlreadyFriend)?;
+
+        let info = apply_info(apply, &me, &target);
+        let mut notify = OperateFriendReply::new();
+        notify.type_ = FriendOperationType::RECV_ADD_APPLY.into();
+        notify.apply = MessageField::some((&info).into());
+        self.push(&target.user_id, notify).await;
+        Ok(info)
+    }
+
+    async fn list_applies(&self, user_id: &str) -> Result<Vec<FriendApplyInfo>> {
+        let me = self.find_user(user_id).await?;
+        let res = async {
+            let applies = self.apply_repo.find_by_user(me.id, APPLY_LIST_LIMIT).await?;
+            let ids: Vec<i64> = applies
+                .iter()
+                .map(|a| {
+                    if a.from_user_id == me.id {
+                        a.to_user_id
+                    } else {
+                        a.from_user_id
+                    }
+                })
+                .collect();
+            let users = self.user_repo.find_by_ids(&ids).await?;
+            Ok((applies, users))
+        }
+        .await;
+        let (applies, users) = res.map_err(|err: sea_orm::DbErr| {
+            tracing::error!("load friend applies of user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+
+        // 对方账号已被删除的申请不再返回
+        let list = applies
+            .into_iter()
+            .filter_map(|apply| {
+                let (from, to) = if apply.from_user_id == me.id {
+                    (&me, users.iter().find(|u| u.id == apply.to_user_id)?)
+                } else {
+                    (users.iter().find(|u| u.id == apply.from_user_id)?, &me)
+                };
+                Some(apply_info(apply, from, to))
+            })
+            .collect();
+        Ok(list)
+    }
+
+    async fn reply_apply(
+        &self,
+        user_id: &str,
+        apply_id: i64,
+        answer: FriendOperationApplyType,
+    ) -> Result<FriendApplyInfo> {
+        if answer == FriendOperationApplyType::APPLY_UNKNOWN {
+            return Err(Error::ParamInvalid("请选择接受或拒绝".to_string()));
+        }
+        let me = self.find_user(user_id).await?;
+        let apply = self
+            .apply_repo
+            .find_by_id(apply_id)
+            .await
+            .map_err(|err| {
+                tracing::error!("load friend apply {} failed, {err:#}", apply_id);
+                Error::InternalServerError
+            })?
+            .filter(|apply| apply.to_user_id == me.id)
+            .ok_or(Error::ParamInvalid("好友申请不存在".to_string()))?;
+        if apply.status != STATUS_PENDING {
+            return Err(Error::ParamInvalid("好友申请已处理".to_string()));
+        }
+        let from = self
+            .user_repo
+            .find_by_id(apply.from_user_id)
+            .await
+            .map_err(|err| {
+                tracing::error!("load user {} failed, {err:#}", apply.from_user_id);
+                Error::InternalServerError
+            })?
+            .ok_or(Error::UserNotRegistered)?;
+
+        let friends = (answer == FriendOperationApplyType::APPLY_ACCEPTED).then_some((from.id, me.id));
+        let answered = self.apply_repo.answer(apply.id, answer.value(), friends).await.map_err(|err| {
+            tracing::error!("reply friend apply {} failed, {err:#}", apply_id);
+            Error::InternalServerError
+        })?;
+        if !answered {
+            return Err(Error::ParamInvalid("好友申请已处理".to_string()));
+        }
+        tracing::info!(
+            "user {} {:?} friend apply {} from {}",
+            me.user_id,
+            answer,
+            apply_id,
+            from.user_id
+        );
+
+        let mut info = apply_info(apply, &from, &me);
+        info.status = answer.into();
+        let mut notify = OperateFriendReply::new();
+        notify.type_ = FriendOperationType::REPLY_ADD_APPLY.into();
+        notify.apply = MessageField::some((&info).into());
+        self.push(&from.user_id, notify).await;
+        Ok(info)
+    }
+
+    async fn delete_friend(&self, user_id: &str, friend_user_id: &str) -> Result<PublicProfile> {
+        let me = self.find_user(user_id).await?;
+        // 对方账号状态不影响解除好友关系
+        let friend = self
+            .user_repo
+            .find_by_user_id(friend_user_id)
+            .await
+            .map_err(|err| {
+                tracing::error!("load user {} failed, {err:#}", friend_user_id);
+                Error::InternalServerError
+            })?
+            .ok_or(Error::UserNotRegistered)?;
+        let removed = self.relationship_repo.remove(me.id, friend.id).await.map_err(|err| {
+            tracing::error!(
+                "delete friend {} of user {} failed, {err:#}",
+                friend_user_id,
+                user_id
+            );
+            Error::InternalServerError
+        })?;
+        if !removed {
+            return Err(Error::NotFriend);
+        }
+        tracing::info!("user {} delete friend {}", user_id, friend_user_id);
+
+        let mut notify = OperateFriendReply::new();
+        notify.type_ = FriendOperationType::RECV_DELETE_APPLY.into();
+        notify.user = MessageField::some((&PublicProfile::from(&me)).into());
+        self.push(&friend.user_id, notify).await;
+        Ok(PublicProfile::from(&friend))
+    }
+
+    async fn can_chat(&self, sender_id: &str, target_id: &str) -> Result<bool> {
+        let policy = self.cfg.chat.stranger_policy;
+        if policy == StrangerChatPolicy::Allow || sender_id == target_id {
+            return Ok(true);
+        }
+        let res = async {
+            let sender = self.user_repo.find_by_user_id(sender_id).await?;
+            let target = self.user_repo.find_by_user_id(target_id).await?;
+            match (sender, target) {
+                (Some(sender), Some(target)) => self.relationship_repo.exists(sender.id, target.id).await,
+                _ => Ok(false),
+            }
+        }
+        .await;
+        let is_friend = res.map_err(|err: sea_orm::DbErr| {
+            tracing::error!(
+                "check friendship of {} and {} failed, {err:#}",
+                sender_id,
+                target_id
+            );
+            Error::InternalServerError
+        })?;
+        match (is_friend, policy) {
+            (true, _) => Ok(true),
+            (false, StrangerChatPolicy::Drop) => Ok(false),
+            (false, _) => Err(Error::NotFriend),
+        }
+    }
+
+    async fn list_friends(&self, user_id: &str, since: Option<i64>) -> Result<FriendList> {
+        let me = self.find_user(user_id).await?;
+        let since = since.filter(|v| *v > 0).and_then(DateTime::from_timestamp_millis);
+        let res = async {
+            let rows = match since {
+                Some(since) => self.relationship_repo.find_changed(me.id, since).await?,
+                None => self.relationship_repo.find_by_user(me.id).await?,
+            };
+            let ids: Vec<i64> = rows.iter().map(|r| r.friend_id).collect();
+            let users = self.user_repo.find_by_ids(&ids).await?;
+            let team_names = self.team_repo.find_by_user(me.id).await?.into_iter().map(|t| t.name);
+            let order: Vec<String> =
+                std::iter::once(DEFAULT_GROUP_NAME.to_string()).chain(team_names).collect();
+            Ok((rows, users, order))
+        }
+        .await;
+        let (rows, users, order) = res.map_err(|err: sea_orm::DbErr| {
+            tracing::error!("load friends of user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+
+        // 时间戳按秒存储, 下次同步会再次返回同一秒内的变化
+        let version = rows
+            .iter()
+            .map(|r| r.update_time)
+            .max()
+            .or(since)
+            .map_or(0, |t| t.timestamp_millis());
+        // 全量同步时包含空分组
+        let mut teams: Vec<FriendTeam> = match since {
+            Some(_) => Vec::new(),
+            None => order
+                .iter()
+                .map(|name| FriendTeam {
+                    name: name.clone(),
+                    friends: Vec::new(),
+                })
+                .collect(),
+        };
+        let users: HashMap<i64, user::Model> = users.into_iter().map(|u| (u.id, u)).collect();
+        // 并发查询在线状态, 请求在同一个Redis连接上批量发出
+        let online: HashMap<i64, chatmsg::OnlineStatus> = join_all(
+            rows.iter()
+                .filter(|r| !r.deleted)
+                .filter_map(|r| users.get(&r.friend_id))
+                .map(|u| async move { (u.id, self.online_status(&u.user_id).await) }),
+        )
+        .await
+        .into_iter()
+        .collect();
+        let mut removed = Vec::new();
+        for row in rows {
+            // 对方账号已被删除
+            let Some(friend) = users.get(&row.friend_id) else {
+                continue;
+            };
+            if row.deleted {
+                removed.push(friend.user_id.clone());
+                continue;
+            }
+            let info = FriendInfo {
+                profile: friend.into(),
+                mark_name: row.mark_name,
+                status: online.get(&friend.id).copied().unwrap_or(chatmsg::OnlineStatus::OFFLINE).into(),
+            };
+            match teams.iter_mut().find(|t| t.name == row.group_name) {
+                Some(team) => team.friends.push(info),
+                None => teams.push(FriendTeam {
+                    name: row.group_name,
+                    friends: vec![info],
+                }),
+            }
+        }
+        // 按分组顺序排列, 默认分组在最前
+        teams.sort_by_key(|t| order.iter().position(|name| name == &t.name).unwrap_or(order.len()));
+        Ok(FriendList {
+            version,
+            teams,
+            removed,
+        })
+    }
+}
+
+fn apply_info(apply: friend_apply::Model, from: &user::Model, to: &user::Model) -> FriendApplyInfo {
+    let status = FriendOperationApplyType::from_i32(apply.status).unwrap_or_default();
+    FriendApplyInfo {
+        apply_id: apply.id,
+        from: from.into(),
+        to: to.into(),
+        message: apply.message,
+        status: status.into(),
+        create_time: apply.create_time,
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{DbErr, SqlErr};
+use serde::{Deserialize, Serialize};
+use shaku::{Component, Interface};
+
+use crate::base::response::{Error, Result};
+use crate::db::entity::friend_team;
+use crate::db::repository::friend_team::IFriendTeamRepository;
+use crate::db::repository::relationship::DEFAULT_GROUP_NAME;
+use crate::db::repository::user::IUserRepository;
+
+/// 除默认分组外最多可创建的分组数
+const MAX_TEAMS: usize = 50;
+/// 分组名称最大长度, 与好友关系表中的分组字段一致
+const MAX_NAME_LEN: usize = 32;
+
+#[derive(Debug, Deserialize)]
+pub struct AddTeamRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RenameTeamRequest {
+    pub new_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReorderTeamsRequest {
+    /// 调整后的分组顺序, 需包含全部分组, 默认分组始终在最前
+    pub names: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TeamList {
+    /// 全部分组名称, 默认分组在最前
+    pub teams: Vec<String>,
+}
+
+#[async_trait]
+pub trait IFriendTeamService: Interface {
+    async fn list_teams(&self, user_id: &str) -> Result<TeamList>;
+    /// 新建分组, 排在最后
+    async fn add_team(&self, user_id: &str, name: &str) -> Result<TeamList>;
+    /// 重命名分组, 默认分组不能重命名
+    async fn rename_team(&self, user_id: &str, name: &str, new_name: &str) -> Result<TeamList>;
+    /// 删除分组, 分组下的好友移到默认分组
+    async fn delete_team(&self, user_id: &str, name: &str) -> Result<TeamList>;
+    async fn reorder_teams(&self, user_id: &str, names: Vec<String>) -> Result<TeamList>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IFriendTeamService)]
+pub struct FriendTeamServiceImpl {
+    #[shaku(inject)]
+    user_repo: Arc<dyn IUserRepository>,
+    #[shaku(inject)]
+    team_repo: Arc<dyn IFriendTeamRepository>,
+}
+
+impl FriendTeamServiceImpl {
+    /// 用户的数据库ID
+    async fn find_user(&self, user_id: &str) -> Result<i64> {
+        let user = self.user_repo.find_by_user_id(user_id).await.map_err(|err| {
+            tracing::error!("load user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+        user.map(|u| u.id).ok_or(Error::UserNotRegistered)
+    }
+
+    async fn teams(&self, id: i64) -> Result<Vec<String>> {
+        let teams = self.team_repo.find_by_user(id).await.map_err(|err| {
+            tracing::error!("load friend teams of user {} failed, {err:#}", id);
+            Error::InternalServerError
+        })?;
+        Ok(teams.into_iter().map(|t| t.name).collect())
+    }
+
+    async fn team_list(&self, id: i64) -> Result<TeamList> {
+        let mut teams = vec![DEFAULT_GROUP_NAME.to_string()];
+        teams.extend(self.teams(id).await?);
+        Ok(TeamList { teams })
+    }
+}
+
+#[async_trait]
+impl IFriendTeamService for FriendTeamServiceImpl {
+    async fn list_teams(&self, user_id: &str) -> Result<TeamList> {
+        let id = self.find_user(user_id).await?;
+        self.team_list(id).await
+    }
+
+    async fn add_team(&self, user_id: &str, name: &str) -> Result<TeamList> {
+        let name = check_name(name)?;
+        let id = self.find_user(user_id).await?;
+        let teams = self.teams(id).await?;
+        if name == DEFAULT_GROUP_NAME || teams.contains(&name) {
+            return Err(Error::TeamNameDuplicate);
+        }
+        if teams.len() >= MAX_TEAMS {
+            return Err(Error::ParamInvalid(format!("最多创建{MAX_TEAMS}个分组")));
+        }
+        let team = friend_team::ActiveModel {
+            user_id: Set(id),
+            name: Set(name),
+            sort: Set(teams.len() as i32),
+            ..Default::default()
+        };
+        self.team_repo.add(team).await.map_err(|err| map_db_err(err, user_id))?;
+        self.team_list(id).await
+    }
+
+    async fn rename_team(&self, user_id: &str, name: &str, new_name: &str) -> Result<TeamList> {
+        if name == DEFAULT_GROUP_NAME {
+            return Err(Error::ParamInvalid("默认分组不能修改".to_string()));
+        }
+        let new_name = check_name(new_name)?;
+        let id = self.find_user(user_id).await?;
+        if new_name == name {
+            return self.team_list(id).await;
+        }
+        if new_name == DEFAULT_GROUP_NAME || self.teams(id).await?.contains(&new_name) {
+            return Err(Error::TeamNameDuplicate);
+        }
+        let renamed = self
+            .team_repo
+            .rename(id, name, &new_name)
+            .await
+            .map_err(|err| map_db_err(err, user_id))?;
+        if !renamed {
+            return Err(Error::ParamInvalid("分组不存在".to_string()));
+        }
+        self.team_list(id).await
+    }
+
+    async fn delete_team(&self, user_id: &str, name: &str) -> Result<TeamList> {
+        if name == DEFAULT_GROUP_NAME {
+            return Err(Error::ParamInvalid("默认分组不能删除".to_string()));
+        }
+        let id = self.find_user(user_id).await?;
+        let deleted = self.team_repo.delete(id, name).await.map_err(|err| map_db_err(err, user_id))?;
+        if !deleted {
+            return Err(Error::ParamInvalid("分组不存在".to_string()));
+        }
+        self.team_list(id).await
+    }
+
+    async fn reorder_teams(&self, user_id: &str, names: Vec<String>) -> Result<TeamList> {
+        let id = self.find_user(user_id).await?;
+        let names: Vec<String> = names.into_iter().filter(|n| n != DEFAULT_GROUP_NAME).collect();
+        let teams = self.teams(id).await?;
+        let unique: HashSet<&String> = names.iter().collect();
+        if names.len() != teams.len()
+            || unique.len() != names.len()
+            || !teams.iter().all(|t| unique.contains(t))
+        {
+            return Err(Error::ParamInvalid("分组已变化, 请刷新后重试".to_string()));
+        }
+        self.team_repo
+            .reorder(id, &names)
+            .await
+            .map_err(|err| map_db_err(err, user_id))?;
+        self.team_list(id).await
+    }
+}
+
+/// 去掉首尾空白后校验分组名称
+fn check_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error::ParamInvalid("分组名称不能为空".to_string()));
+    }
+    if name.chars().count() > MAX_NAME_LEN {
+        return Err(Error::ParamInvalid(format!("分组名称最多{MAX_NAME_LEN}个字符")));
+    }
+    Ok(name.to_string())
+}
+
+/// 并发创建或重命名为同一名称时由唯一索引拦截
+fn map_db_err(err: DbErr, user_id: &str) -> Error {
+    if let Some(SqlErr::UniqueConstraintViolation(_)) = err.sql_err() {
+        return Error::TeamNameDuplicate;
+    }
+    tracing::error!("update friend teams of user {} failed, {err:#}", user_id);
+    Error::InternalServerError
+}
@@ -0,0 +1,256 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use fred::prelude::{KeysInterface, LuaInterface};
+use serde::{Deserialize, Serialize};
+use shaku::{Component, Interface};
+
+use crate::base::config::Config;
+use crate::base::response::{Error, Result};
+use crate::components::redis::IRedisService;
+
+/// 默认失败次数统计窗口
+const DEFAULT_WINDOW: Duration = Duration::from_secs(15 * 60);
+/// 默认同一用户名锁定阈值
+const DEFAULT_USER_THRESHOLD: u32 = 5;
+/// 默认同一IP锁定阈值
+const DEFAULT_IP_THRESHOLD: u32 = 50;
+/// 默认锁定时长
+const DEFAULT_LOCK_DURATION: Duration = Duration::from_secs(15 * 60);
+
+const FAIL_KEY_PREFIX: &str = "login:fail:";
+const LOCK_KEY_PREFIX: &str = "login:lock:";
+/// 当前锁定的索引, 成员为`{kind}:{value}`, 分数为解锁时间(毫秒)
+const LOCKOUTS_KEY: &str = "login:lockouts";
+
+/// 滑动窗口内记录一次失败, 达到阈值时锁定并清空窗口. 返回窗口内的失败次数
+const RECORD_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window)
+redis.call('ZADD', KEYS[1], now, ARGV[3])
+redis.call('PEXPIRE', KEYS[1], window)
+local count = redis.call('ZCARD', KEYS[1])
+if count >= tonumber(ARGV[4]) then
+    redis.call('SET', KEYS[2], count, 'EX', ARGV[5])
+    redis.call('ZADD', KEYS[3], now + tonumber(ARGV[5]) * 1000, ARGV[6])
+    redis.call('DEL', KEYS[1])
+end
+return count
+"#;
+
+/// 清理已过期的锁定后返回全部锁定及解锁时间
+const LIST_SCRIPT: &str = r#"
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', ARGV[1])
+return redis.call('ZRANGE', KEYS[1], 0, -1, 'WITHSCORES')
+"#;
+
+/// 解除锁定并清空失败计数. 返回1表示解除前处于锁定状态
+const UNLOCK_SCRIPT: &str = r#"
+local locked = redis.call('DEL', KEYS[1])
+redis.call('DEL', KEYS[2])
+redis.call('ZREM', KEYS[3], ARGV[1])
+return locked
+"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockKind {
+    User,
+    Ip,
+}
+
+impl LockKind {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "user" => Some(LockKind::User),
+            "ip" => Some(LockKind::Ip),
+            _ => None,
+        }
+    }
+}
+
+impl Display for LockKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LockKind::User => write!(f, "user"),
+            LockKind::Ip => write!(f, "ip"),
+        }
+    }
+}
+
+/// 一条锁定记录, 供监控接口查看
+#[derive(Debug, Serialize)]
+pub struct Lockout {
+    pub kind: LockKind,
+    pub value: String,
+    /// 剩余锁定时间(秒)
+    pub expires_in: u64,
+}
+
+#[async_trait]
+pub trait ILoginGuardService: Interface {
+    /// 登录前检查用户名及IP是否处于锁定状态
+    async fn check(&self, username: &str, ip: &str) -> Result<()>;
+    /// 记录一次密码登录失败, 达到阈值时锁定
+    async fn record_failure(&self, username: &str, ip: &str);
+    /// 登录成功后清除该用户名的失败计数
+    async fn reset(&self, username: &str);
+    /// 当前全部锁定
+    async fn lockouts(&self) -> Result<Vec<Lockout>>;
+    /// 手动解除锁定, 返回解除前是否处于锁定状态
+    async fn unlock(&self, kind: LockKind, value: &str) -> Result<bool>;
+}
+
+#[derive(Component)]
+#[shaku(interface = ILoginGuardService)]
+pub struct LoginGuardServiceImpl {
+    #[shaku(no_default)]
+    cfg: Arc<Config>,
+    #[shaku(inject)]
+    redis_cli: Arc<dyn IRedisService>,
+}
+
+impl LoginGuardServiceImpl {
+    /// 锁定剩余时间, 未锁定时返回None
+    async fn locked_for(&self, kind: LockKind, value: &str) -> Result<Option<u64>> {
+        let ttl: i64 = self
+            .redis_cli
+            .get_conn()
+            .ttl(format!("{LOCK_KEY_PREFIX}{kind}:{value}"))
+            .await
+            .map_err(|err| {
+                tracing::error!("check login lock of {} {} failed, {err:#}", kind, value);
+                Error::InternalServerError
+            })?;
+        Ok((ttl > 0).then_some(ttl as u64))
+    }
+
+    async fn record(&self, kind: LockKind, value: &str, threshold: u32) {
+        let cfg = &self.cfg.login_guard;
+        let window = cfg.window.unwrap_or(DEFAULT_WINDOW);
+        let lock_duration = cfg.lock_duration.unwrap_or(DEFAULT_LOCK_DURATION);
+        let now = chrono::Utc::now().timestamp_millis();
+        let member = format!("{kind}:{value}");
+        let res: std::result::Result<i64, _> = self
+            .redis_cli
+            .get_conn()
+            .eval(
+                RECORD_SCRIPT,
+                vec![
+                    format!("{FAIL_KEY_PREFIX}{member}"),
+                    format!("{LOCK_KEY_PREFIX}{member}"),
+                    LOCKOUTS_KEY.to_string(),
+                ],
+                vec![
+                    now.to_string(),
+                    (window.as_millis().max(1) as i64).to_string(),
+                    uuid::Uuid::new_v4().simple().to_string(),
+                    threshold.max(1).to_string(),
+                    lock_duration.as_secs().max(1).to_string(),
+                    member.clone(),
+                ],
+            )
+            .await;
+        match res {
+            Ok(count) if count >= threshold.max(1) as i64 => {
+                tracing::warn!(
+                    "{} locked for {:?} after {} failed logins",
+                    member,
+                    lock_duration,
+                    count
+                );
+            }
+            Ok(_) => {}
+            Err(err) => tracing::error!("record failed login of {} failed, {err:#}", member),
+        }
+    }
+}
+
+#[async_trait]
+impl ILoginGuardService for LoginGuardServiceImpl {
+    async fn check(&self, username: &str, ip: &str) -> Result<()> {
+        if let Some(ttl) = self.locked_for(LockKind::User, username).await? {
+            return Err(Error::AccountLocked(ttl));
+        }
+        if !ip.is_empty() && self.locked_for(LockKind::Ip, ip).await?.is_some() {
+            return Err(Error::TooManyRequests);
+        }
+        Ok(())
+    }
+
+    async fn record_failure(&self, username: &str, ip: &str) {
+        let cfg = &self.cfg.login_guard;
+        self.record(
+            LockKind::User,
+            username,
+            cfg.user_threshold.unwrap_or(DEFAULT_USER_THRESHOLD),
+        )
+        .await;
+        if !ip.is_empty() {
+            self.record(LockKind::Ip, ip, cfg.ip_threshold.unwrap_or(DEFAULT_IP_THRESHOLD))
+                .await;
+        }
+    }
+
+    async fn reset(&self, username: &str) {
+        // IP的计数不随登录成功清除, 避免用自己的账号刷掉撞库的计数
+        let key = format!("{FAIL_KEY_PREFIX}{}:{username}", LockKind::User);
+        if let Err(err) = self.redis_cli.get_conn().del::<(), _>(key).await {
+            tracing::error!("reset failed logins of {} failed, {err:#}", username);
+        }
+    }
+
+    async fn lockouts(&self) -> Result<Vec<Lockout>> {
+        let now = chrono::Utc::now().timestamp_millis();
+        let items: Vec<String> = self
+            .redis_cli
+            .get_conn()
+            .eval(LIST_SCRIPT, LOCKOUTS_KEY, now)
+            .await
+            .map_err(|err| {
+                tracing::error!("list login lockouts failed, {err:#}");
+                Error::InternalServerError
+            })?;
+        let lockouts = items
+            .chunks_exact(2)
+            .filter_map(|pair| {
+                let (kind, value) = pair[0].split_once(':')?;
+                let unlock_at = pair[1].parse::<f64>().ok()? as i64;
+                Some(Lockout {
+                    kind: LockKind::parse(kind)?,
+                    value: value.to_string(),
+                    expires_in: ((unlock_at - now).max(0) as u64).div_ceil(1000),
+                })
+            })
+            .collect();
+        Ok(lockouts)
+    }
+
+    async fn unlock(&self, kind: LockKind, value: &str) -> Result<bool> {
+        let member = format!("{kind}:{value}");
+        let locked: i64 = self
+            .redis_cli
+            .get_conn()
+            .eval(
+                UNLOCK_SCRIPT,
+                vec![
+                    format!("{LOCK_KEY_PREFIX}{member}"),
+                    format!("{FAIL_KEY_PREFIX}{member}"),
+                    LOCKOUTS_KEY.to_string(),
+                ],
+                vec![member.clone()],
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("unlock {} failed, {err:#}", member);
+                Error::InternalServerError
+            })?;
+        if locked == 1 {
+            tracing::info!("{} unlocked manually", member);
+        }
+        Ok(locked == 1)
+    }
+}
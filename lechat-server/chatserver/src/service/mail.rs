@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use tokio::io::AsyncWriteExt;
+
+use crate::base::config::{MailConfig, SmtpConfig, SmtpSecurity};
+use crate::base::response::{Error, Result};
+
+/// 默认发件人
+const DEFAULT_FROM: &str = "LeChat <noreply@lechat.example.com>";
+
+/// 邮件发送通道
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<()>;
+}
+
+/// 将邮件写入日志, 用于本地测试
+pub struct LogMailer;
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<()> {
+        tracing::info!("mail to {}: [{}] {}", to, subject, body);
+        Ok(())
+    }
+}
+
+/// 将邮件追加到文件, 用于本地测试
+pub struct FileMailer {
+    path: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(path: PathBuf) -> Self {
+        FileMailer { path }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<()> {
+        let content = format!(
+            "Date: {}\nTo: {}\nSubject: {}\n\n{}\n\n",
+            chrono::Local::now().to_rfc3339(),
+            to,
+            subject,
+            body
+        );
+        let res = async {
+            let mut file = tokio::fs::OpenOptions::new().create(true).append(true).open(&self.path).await?;
+            file.write_all(content.as_bytes()).await
+        }
+        .await;
+        res.map_err(|err| {
+            tracing::error!("write mail to {} failed, {err:#}", self.path.display());
+            Error::InternalServerError
+        })
+    }
+}
+
+/// 通过SMTP发送邮件
+pub struct SmtpMailer {
+    from: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    pub fn new(cfg: &SmtpConfig, from: Mailbox) -> anyhow::Result<Self> {
+        let builder = match cfg.security {
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&cfg.host)?,
+            SmtpSecurity::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&cfg.host)?,
+            SmtpSecurity::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&cfg.host),
+        };
+        let builder = match cfg.port {
+            Some(port) => builder.port(port),
+            None => builder,
+        };
+        let builder = match (&cfg.username, &cfg.password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username.clone(), password.clone()))
+            }
+            _ => builder,
+        };
+        Ok(SmtpMailer {
+            from,
+            transport: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<()> {
+        let to = to.parse::<Mailbox>().map_err(|err| {
+            tracing::error!("invalid mail recipient {}, {err:#}", to);
+            Error::ParamInvalid("邮箱格式不正确".to_string())
+        })?;
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body.to_string())
+            .map_err(|err| {
+                tracing::error!("build mail failed, {err:#}");
+                Error::InternalServerError
+            })?;
+        self.transport.send(message).await.map_err(|err| {
+            tracing::error!("send mail by smtp failed, {err:#}");
+            Error::InternalServerError
+        })?;
+        Ok(())
+    }
+}
+
+/// 根据配置选择邮件发送通道
+pub fn mailer(cfg: &MailConfig) -> anyhow::Result<Arc<dyn Mailer>> {
+    if let Some(smtp) = &cfg.smtp {
+        let from = cfg.from.as_deref().unwrap_or(DEFAULT_FROM);
+        let from = from.parse::<Mailbox>().with_context(|| format!("invalid mail sender {from}"))?;
+        let mailer =
+            SmtpMailer::new(smtp, from).with_context(|| format!("init smtp mailer {} failed", smtp.host))?;
+        return Ok(Arc::new(mailer));
+    }
+    Ok(match &cfg.outbox_path {
+        Some(path) => Arc::new(FileMailer::new(path.clone())),
+        None => Arc::new(LogMailer),
+    })
+}
@@ -1,10 +1,23 @@
 use std::sync::Arc;
 
+use sha2::{Digest, Sha256};
+
 use crate::base::response::{Error, Result};
 use crate::components::{get_service_factory, Modules};
 
-pub mod checker;
+pub mod audit;
+pub mod friend;
+pub mod friend_team;
+pub mod login_guard;
+pub mod mail;
+pub mod password;
+pub mod password_reset;
+pub mod presence;
+pub mod reservation;
+pub mod sms;
+pub mod token;
 pub mod user;
+pub mod verification;
 
 #[inline]
 pub fn service_factory() -> Result<Arc<Modules>> {
@@ -13,3 +26,8 @@ pub fn service_factory() -> Result<Arc<Modules>> {
         Error::InternalServerError
     })
 }
+
+/// 一次性令牌只保存摘要, Redis数据泄露时令牌无法直接使用
+pub(crate) fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use shaku::{Component, Interface};
+use subtle::ConstantTimeEq;
+
+use crate::base::config::{Config, PasswordConfig};
+use crate::base::response::{Error, Result};
+
+/// 密码校验结果
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verification {
+    /// 密码错误
+    Mismatch,
+    /// 密码正确
+    Valid,
+    /// 密码正确, 但存储的是明文或参数较弱的哈希, 需要重新哈希
+    Outdated,
+}
+
+pub trait IPasswordService: Interface {
+    /// 生成加盐的Argon2id哈希, PHC字符串格式
+    fn hash(&self, password: &str) -> Result<String>;
+    /// 校验密码, 兼容历史数据中的明文密码
+    fn verify(&self, password: &str, stored: &str) -> Result<Verification>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IPasswordService)]
+pub struct PasswordServiceImpl {
+    #[shaku(no_default)]
+    cfg: Arc<Config>,
+}
+
+impl IPasswordService for PasswordServiceImpl {
+    fn hash(&self, password: &str) -> Result<String> {
+        hash_password(&self.cfg.password, password)
+    }
+
+    fn verify(&self, password: &str, stored: &str) -> Result<Verification> {
+        verify_password(&self.cfg.password, password, stored)
+    }
+}
+
+/// 按配置的参数构造Argon2id
+fn argon2(cfg: &PasswordConfig) -> Result<Argon2<'static>> {
+    let params = Params::new(
+        cfg.memory_cost.unwrap_or(Params::DEFAULT_M_COST),
+        cfg.time_cost.unwrap_or(Params::DEFAULT_T_COST),
+        cfg.parallelism.unwrap_or(Params::DEFAULT_P_COST),
+        None,
+    )
+    .map_err(|err| {
+        tracing::error!("invalid argon2 params, {err:#}");
+        Error::InternalServerError
+    })?;
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
+
+fn hash_password(cfg: &PasswordConfig, password: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = argon2(cfg)?.hash_password(password.as_bytes(), &salt).map_err(|err| {
+        tracing::error!("hash password failed, {err:#}");
+        Error::InternalServerError
+    })?;
+    Ok(hash.to_string())
+}
+
+fn verify_password(cfg: &PasswordConfig, password: &str, stored: &str) -> Result<Verification> {
+    let Ok(hash) = PasswordHash::new(stored) else {
+        // 历史数据中的明文密码
+        let valid: bool = password.as_bytes().ct_eq(stored.as_bytes()).into();
+        return Ok(if valid {
+            Verification::Outdated
+        } else {
+            Verification::Mismatch
+        });
+    };
+    // 按哈希中记录的算法及参数校验, 比较过程是常量时间的
+    if Argon2::default().verify_password(password.as_bytes(), &hash).is_err() {
+        return Ok(Verification::Mismatch);
+    }
+
+    let argon2 = argon2(cfg)?;
+    let current = hash.algorithm == Algorithm::Argon2id.ident()
+        && hash.version == Some(Version::V0x13.into())
+        && Params::try_from(&hash).is_ok_and(|params| {
+            params.m_cost() >= argon2.params().m_cost()
+                && params.t_cost() >= argon2.params().t_cost()
+                && params.p_cost() >= argon2.params().p_cost()
+        });
+    Ok(if current {
+        Verification::Valid
+    } else {
+        Verification::Outdated
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试用的较小参数, 避免默认参数拖慢测试
+    fn cfg(memory_cost: u32, time_cost: u32) -> PasswordConfig {
+        PasswordConfig {
+            memory_cost: Some(memory_cost),
+            time_cost: Some(time_cost),
+            parallelism: Some(1),
+        }
+    }
+
+    #[test]
+    fn hash_then_verify() {
+        let cfg = cfg(1024, 1);
+        let hash = hash_password(&cfg, "abcd1234").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert_eq!(
+            verify_password(&cfg, "abcd1234", &hash).unwrap(),
+            Verification::Valid
+        );
+    }
+
+    #[test]
+    fn hash_is_salted() {
+        let cfg = cfg(1024, 1);
+        assert_ne!(
+            hash_password(&cfg, "abcd1234").unwrap(),
+            hash_password(&cfg, "abcd1234").unwrap()
+        );
+    }
+
+    #[test]
+    fn reject_wrong_password() {
+        let cfg = cfg(1024, 1);
+        let hash = hash_password(&cfg, "abcd1234").unwrap();
+        assert_eq!(
+            verify_password(&cfg, "abcd12345", &hash).unwrap(),
+            Verification::Mismatch
+        );
+    }
+
+    #[test]
+    fn plaintext_is_outdated() {
+        let cfg = cfg(1024, 1);
+        assert_eq!(
+            verify_password(&cfg, "abcd1234", "abcd1234").unwrap(),
+            Verification::Outdated
+        );
+        assert_eq!(
+            verify_password(&cfg, "abcd1234", "abcd12345").unwrap(),
+            Verification::Mismatch
+        );
+    }
+
+    #[test]
+    fn weaker_params_are_outdated() {
+        let hash = hash_password(&cfg(1024, 1), "abcd1234").unwrap();
+        assert_eq!(
+            verify_password(&cfg(2048, 1), "abcd1234", &hash).unwrap(),
+            Verification::Outdated
+        );
+        assert_eq!(
+            verify_password(&cfg(1024, 2), "abcd1234", &hash).unwrap(),
+            Verification::Outdated
+        );
+        // 参数不弱于当前配置时不需要重新哈希
+        assert_eq!(
+            verify_password(&cfg(512, 1), "abcd1234", &hash).unwrap(),
+            Verification::Valid
+        );
+    }
+
+    #[test]
+    fn other_algorithm_is_outdated() {
+        let salt = SaltString::generate(&mut OsRng);
+        let params = Params::new(1024, 1, 1, None).unwrap();
+        let hash = Argon2::new(Algorithm::Argon2i, Version::V0x13, params)
+            .hash_password(b"abcd1234", &salt)
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            verify_password(&cfg(1024, 1), "abcd1234", &hash).unwrap(),
+            Verification::Outdated
+        );
+    }
+}
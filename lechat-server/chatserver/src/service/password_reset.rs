@@ -0,0 +1,234 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use fred::prelude::{Expiration, KeysInterface, LuaInterface, SetOptions};
+use sea_orm::ActiveValue::Set;
+use serde::Deserialize;
+use shaku::{Component, Interface};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::base::config::Config;
+use crate::base::response::{Error, Result};
+use crate::components::redis::IRedisService;
+use crate::db::entity::user as entity;
+use crate::db::repository::user::IUserRepository;
+use crate::service::audit::{AuditAction, AuditSource, IAuditService};
+use crate::service::hash_token;
+use crate::service::login_guard::{ILoginGuardService, LockKind};
+use crate::service::mail::Mailer;
+use crate::service::password::IPasswordService;
+use crate::service::user::IUserService;
+
+/// 默认重置令牌有效期
+const DEFAULT_TOKEN_TTL: Duration = Duration::from_secs(30 * 60);
+/// 默认同一邮箱两次申请的最小间隔
+const DEFAULT_RESEND_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 令牌摘要到用户ID
+const TOKEN_KEY_PREFIX: &str = "pwd_reset:token:";
+/// 用户ID到当前有效令牌的摘要, 新令牌签发后旧令牌失效
+const USER_KEY_PREFIX: &str = "pwd_reset:user:";
+const COOLDOWN_KEY_PREFIX: &str = "pwd_reset:cooldown:";
+
+/// 保存新令牌并作废该用户之前的令牌
+const SAVE_SCRIPT: &str = r#"
+local old = redis.call('GET', KEYS[2])
+if old then
+    redis.call('DEL', ARGV[4] .. old)
+end
+redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[2])
+redis.call('SET', KEYS[2], ARGV[3], 'EX', ARGV[2])
+"#;
+
+/// 取出并删除令牌, 保证只能使用一次. 返回令牌对应的用户ID
+const CONSUME_SCRIPT: &str = r#"
+local user_id = redis.call('GET', KEYS[1])
+if not user_id then
+    return false
+end
+redis.call('DEL', KEYS[1])
+redis.call('DEL', ARGV[1] .. user_id)
+return user_id
+"#;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct PasswordResetRequest {
+    #[validate(email(message = "Please provide a valid email!"))]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ConfirmPasswordResetRequest {
+    #[validate(length(min = 1, message = "重置令牌不能为空"))]
+    pub token: String,
+    #[validate(custom(function = "crate::service::user::validate_password"))]
+    pub new_password: String,
+}
+
+#[async_trait]
+pub trait IPasswordResetService: Interface {
+    /// 向注册邮箱发送重置链接, 邮箱未注册时同样返回成功, 避免泄露注册信息
+    async fn request_reset(&self, email: &str) -> Result<()>;
+    /// 使用重置令牌设置新密码, 令牌只能使用一次, 成功后全部设备退出登录
+    async fn confirm_reset(&self, confirm_req: ConfirmPasswordResetRequest, ip: Option<String>)
+        -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IPasswordResetService)]
+pub struct PasswordResetServiceImpl {
+    #[shaku(no_default)]
+    cfg: Arc<Config>,
+    #[shaku(no_default)]
+    mailer: Arc<dyn Mailer>,
+    #[shaku(inject)]
+    redis_cli: Arc<dyn IRedisService>,
+    #[shaku(inject)]
+    repo: Arc<dyn IUserRepository>,
+    #[shaku(inject)]
+    password: Arc<dyn IPasswordService>,
+    #[shaku(inject)]
+    audit: Arc<dyn IAuditService>,
+    #[shaku(inject)]
+    login_guard: Arc<dyn ILoginGuardService>,
+    #[shaku(inject)]
+    user_service: Arc<dyn IUserService>,
+}
+
+impl PasswordResetServiceImpl {
+    fn reset_link(&self, token: &str) -> String {
+        match self.cfg.password_reset.reset_url.as_deref() {
+            Some(url) if url.contains('?') => format!("{url}&token={token}"),
+            Some(url) => format!("{url}?token={token}"),
+            None => token.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl IPasswordResetService for PasswordResetServiceImpl {
+    async fn request_reset(&self, email: &str) -> Result<()> {
+        let cfg = &self.cfg.password_reset;
+        let redis_cli = self.redis_cli.get_conn();
+        // 不论邮箱是否注册都先限流, 两种情况的应答保持一致
+        let resend_interval = cfg.resend_interval.unwrap_or(DEFAULT_RESEND_INTERVAL);
+        let cooldown: Option<String> = redis_cli
+            .set(
+                format!("{COOLDOWN_KEY_PREFIX}{}", email.to_lowercase()),
+                1,
+                Some(Expiration::EX(resend_interval.as_secs().max(1) as i64)),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("check password reset cooldown of {} failed, {err:#}", email);
+                Error::InternalServerError
+            })?;
+        if cooldown.is_none() {
+            return Err(Error::TooManyRequests);
+        }
+
+        let user = self.repo.find_by_email(email).await.map_err(|err| {
+            tracing::error!("request_reset failed, {err:#}");
+            Error::InternalServerError
+        })?;
+        let Some(u) = user else {
+            tracing::info!("password reset requested for unregistered email {}", email);
+            return Ok(());
+        };
+
+        let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let digest = hash_token(&token);
+        let ttl = cfg.token_ttl.unwrap_or(DEFAULT_TOKEN_TTL);
+        let token_key = format!("{TOKEN_KEY_PREFIX}{digest}");
+        redis_cli
+            .eval::<(), _, _, _>(
+                SAVE_SCRIPT,
+                vec![token_key.clone(), format!("{USER_KEY_PREFIX}{}", u.user_id)],
+                vec![
+                    u.user_id.clone(),
+                    ttl.as_secs().max(1).to_string(),
+                    digest,
+                    TOKEN_KEY_PREFIX.to_string(),
+                ],
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("save password reset token of user {} failed, {err:#}", u.user_id);
+                Error::InternalServerError
+            })?;
+
+        let body = format!(
+            "{}, 您好:\n\n您正在重置LeChat账号{}的密码, 请在{}分钟内打开以下链接设置新密码:\n\n{}\n\n如果不是您本人操作, 请忽略本邮件.",
+            u.nick_name,
+            u.user_name,
+            ttl.as_secs().div_ceil(60),
+            self.reset_link(&token)
+        );
+        if let Err(err) = self.mailer.send(email, "LeChat 密码重置", &body).await {
+            let _ = redis_cli.del::<(), _>(token_key).await;
+            return Err(err);
+        }
+        tracing::info!("send password reset mail to user {}", u.user_id);
+        Ok(())
+    }
+
+    async fn confirm_reset(
+        &self,
+        confirm_req: ConfirmPasswordResetRequest,
+        ip: Option<String>,
+    ) -> Result<()> {
+        let user_id: Option<String> = self
+            .redis_cli
+            .get_conn()
+            .eval(
+                CONSUME_SCRIPT,
+                format!("{TOKEN_KEY_PREFIX}{}", hash_token(&confirm_req.token)),
+                USER_KEY_PREFIX,
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("consume password reset token failed, {err:#}");
+                Error::InternalServerError
+            })?;
+        let user_id = user_id.ok_or(Error::TokenInvalid)?;
+
+        let u = self
+            .repo
+            .find_by_user_id(&user_id)
+            .await
+            .map_err(|err| {
+                tracing::error!("confirm_reset failed, {err:#}");
+                Error::InternalServerError
+            })?
+            .ok_or(Error::UserNotRegistered)?;
+        let user_pk = u.id;
+        let username = u.user_name.clone();
+        let mut model: entity::ActiveModel = u.into();
+        model.password = Set(Some(self.password.hash(&confirm_req.new_password)?));
+        self.repo.update(model).await.map_err(|err| {
+            tracing::error!("reset password of user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+        self.audit
+            .record(
+                user_pk,
+                AuditAction::ResetPassword,
+                AuditSource {
+                    client_type: None,
+                    ip,
+                },
+            )
+            .await;
+
+        // 旧密码可能已泄露, 全部设备重新登录, 并解除因密码错误导致的锁定
+        self.user_service.sign_out(&user_id, None).await?;
+        if let Err(err) = self.login_guard.unlock(LockKind::User, &username).await {
+            tracing::error!("unlock user {} after password reset failed, {err:#}", username);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use protobuf::MessageField;
+use shaku::{Component, Interface};
+
+use crate::base::response::{Error, Result};
+use crate::db::repository::relationship::IRelationshipRepository;
+use crate::db::repository::user::IUserRepository;
+use crate::network::delivery::IDeliveryService;
+use crate::network::packet::Packet;
+use crate::network::stubs::chatmsg::{ClientType, MsgType, OnlineStatus};
+use crate::network::stubs::user::{StatusChangeType, UserStatusChange};
+use crate::service::user::UserInfo;
+
+#[async_trait]
+pub trait IPresenceService: Interface {
+    /// 通知在线好友用户在某个设备上的状态变化
+    async fn notify_status(&self, user_id: &str, client_type: ClientType, status: OnlineStatus)
+        -> Result<()>;
+    /// 通知在线好友用户的资料变更
+    async fn notify_profile(&self, user: &UserInfo) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IPresenceService)]
+pub struct PresenceServiceImpl {
+    #[shaku(inject)]
+    user_repo: Arc<dyn IUserRepository>,
+    #[shaku(inject)]
+    relationship_repo: Arc<dyn IRelationshipRepository>,
+    #[shaku(inject)]
+    delivery: Arc<dyn IDeliveryService>,
+}
+
+impl PresenceServiceImpl {
+    /// 通知用户的全部在线好友
+    async fn broadcast(&self, user_id: &str, change: &UserStatusChange) -> Result<()> {
+        let packet = Packet::from_message(MsgType::USER_STATUS_CHANGE, change).map_err(|err| {
+            tracing::error!("encode status change failed, {err:#}");
+            Error::InternalServerError
+        })?;
+        for friend in self.friends(user_id).await? {
+            // 状态通知不保证送达, 单个好友失败不影响其他好友
+            let _ = self.delivery.notify(&friend, packet.clone()).await;
+        }
+        Ok(())
+    }
+
+    /// 用户全部好友的user_id
+    async fn friends(&self, user_id: &str) -> Result<Vec<String>> {
+        let res = async {
+            let Some(user) = self.user_repo.find_by_user_id(user_id).await? else {
+                return Ok(Vec::new());
+            };
+            let ids: Vec<i64> = self
+                .relationship_repo
+                .find_by_user(user.id)
+                .await?
+                .into_iter()
+                .map(|r| r.friend_id)
+                .collect();
+            self.user_repo.find_by_ids(&ids).await
+        }
+        .await;
+        let friends = res.map_err(|err| {
+            tracing::error!("load friends of user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+        Ok(friends.into_iter().map(|u| u.user_id).collect())
+    }
+}
+
+#[async_trait]
+impl IPresenceService for PresenceServiceImpl {
+    async fn notify_status(
+        &self,
+        user_id: &str,
+        client_type: ClientType,
+        status: OnlineStatus,
+    ) -> Result<()> {
+        let mut change = UserStatusChange::new();
+        change.user_id = user_id.to_string();
+        change.change_type = StatusChangeType::ONLINE_STATUS.into();
+        change.client_type = client_type.into();
+        change.status = status.into();
+        self.broadcast(user_id, &change).await
+    }
+
+    async fn notify_profile(&self, user: &UserInfo) -> Result<()> {
+        let user_id = &user.base_info.user_id;
+        let mut change = UserStatusChange::new();
+        change.user_id = user_id.clone();
+        change.change_type = StatusChangeType::PROFILE.into();
+        change.profile = MessageField::some(user.into());
+        self.broadcast(user_id, &change).await
+    }
+}
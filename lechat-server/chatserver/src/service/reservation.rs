@@ -0,0 +1,133 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use fred::prelude::{Expiration, KeysInterface, LuaInterface, RedisResult, SetOptions};
+use shaku::{Component, Interface};
+use uuid::Uuid;
+
+use crate::base::response::{Error, Result};
+use crate::components::redis::IRedisService;
+
+/// 预占保留时长, 需覆盖一次注册的处理时间
+const RESERVATION_TTL: Duration = Duration::from_secs(30);
+const RESERVATION_KEY_PREFIX: &str = "reserve:";
+
+/// 仅当值仍是自己的nonce时删除, 避免处理超时后误删别人的预占
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// 需要防重的用户标识
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReserveKind {
+    Username,
+    Phone,
+    Email,
+}
+
+impl ReserveKind {
+    fn name(&self) -> &'static str {
+        match self {
+            ReserveKind::Username => "username",
+            ReserveKind::Phone => "phone",
+            ReserveKind::Email => "email",
+        }
+    }
+
+    /// 该标识已被占用时返回的错误
+    pub fn duplicate_error(&self) -> Error {
+        match self {
+            ReserveKind::Username => Error::UsernameDuplicate,
+            ReserveKind::Phone => Error::PhoneDuplicate,
+            ReserveKind::Email => Error::EmailDuplicate,
+        }
+    }
+}
+
+/// 一次成功的预占
+#[derive(Debug)]
+pub struct Reservation {
+    pub kind: ReserveKind,
+    key: String,
+    nonce: String,
+}
+
+#[async_trait]
+pub trait IReservationService: Interface {
+    /// 原子地预占标识, 已被其他请求预占时返回`None`.
+    /// Redis不可用时降级放行, 由数据库查询兜底
+    async fn reserve(&self, kind: ReserveKind, value: &str) -> Result<Option<Reservation>>;
+    /// 业务成功后确认预占, 数据已落库, 预占到期后自然释放
+    async fn confirm(&self, reservations: Vec<Reservation>);
+    /// 业务失败后回滚预占, 标识立即可被再次使用
+    async fn rollback(&self, reservations: Vec<Reservation>);
+}
+
+#[derive(Component)]
+#[shaku(interface = IReservationService)]
+pub struct ReservationServiceImpl {
+    #[shaku(inject)]
+    redis_cli: Arc<dyn IRedisService>,
+}
+
+#[async_trait]
+impl IReservationService for ReservationServiceImpl {
+    async fn reserve(&self, kind: ReserveKind, value: &str) -> Result<Option<Reservation>> {
+        let reservation = Reservation {
+            kind,
+            key: format!("{RESERVATION_KEY_PREFIX}{}:{value}", kind.name()),
+            nonce: Uuid::new_v4().simple().to_string(),
+        };
+        let res: RedisResult<Option<String>> = self
+            .redis_cli
+            .get_conn()
+            .set(
+                reservation.key.as_str(),
+                reservation.nonce.as_str(),
+                Some(Expiration::EX(RESERVATION_TTL.as_secs() as i64)),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await;
+        match res {
+            Ok(Some(_)) => Ok(Some(reservation)),
+            Ok(None) => {
+                tracing::info!("{} is reserved by another request", reservation.key);
+                Ok(None)
+            }
+            Err(err) => {
+                tracing::error!(
+                    "reserve {} failed, fall back to database check, {err:#}",
+                    reservation.key
+                );
+                Ok(Some(reservation))
+            }
+        }
+    }
+
+    async fn confirm(&self, reservations: Vec<Reservation>) {
+        for reservation in reservations {
+            tracing::debug!("{} confirmed", reservation.key);
+        }
+    }
+
+    async fn rollback(&self, reservations: Vec<Reservation>) {
+        let redis_cli = self.redis_cli.get_conn();
+        for reservation in reservations {
+            let res: RedisResult<i64> = redis_cli
+                .eval(
+                    RELEASE_SCRIPT,
+                    reservation.key.as_str(),
+                    reservation.nonce.as_str(),
+                )
+                .await;
+            if let Err(err) = res {
+                tracing::error!("release {} failed, {err:#}", reservation.key);
+            }
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use async_trait::async_trait;
+use fred::prelude::{Expiration, KeysInterface, LuaInterface, SetOptions};
+use serde::Deserialize;
+use shaku::{Component, Interface};
+use tokio::io::AsyncWriteExt;
+use validator::Validate;
+
+use crate::base::config::{Config, SmsConfig};
+use crate::base::response::{Error, Result};
+use crate::components::redis::IRedisService;
+use crate::service::user::MOBILE_PHONE_REGEX;
+
+/// 默认验证码有效期
+const DEFAULT_CODE_TTL: Duration = Duration::from_secs(5 * 60);
+/// 默认同一手机号两次发送的最小间隔
+const DEFAULT_RESEND_INTERVAL: Duration = Duration::from_secs(60);
+/// 默认同一手机号每小时最多发送次数
+const DEFAULT_PHONE_HOURLY_LIMIT: u32 = 5;
+/// 默认同一IP每小时最多发送次数
+const DEFAULT_IP_HOURLY_LIMIT: u32 = 20;
+/// 默认每个验证码最多校验次数
+const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+/// 发送次数的统计窗口(秒)
+const LIMIT_WINDOW: i64 = 3600;
+
+const CODE_KEY_PREFIX: &str = "sms:code:";
+const COOLDOWN_KEY_PREFIX: &str = "sms:cooldown:";
+const PHONE_LIMIT_KEY_PREFIX: &str = "sms:limit:phone:";
+const IP_LIMIT_KEY_PREFIX: &str = "sms:limit:ip:";
+
+/// 计数加一, 首次计数时设置统计窗口
+const INCR_SCRIPT: &str = r#"
+local count = redis.call('INCR', KEYS[1])
+if count == 1 then
+    redis.call('EXPIRE', KEYS[1], ARGV[1])
+end
+return count
+"#;
+
+/// 保存新验证码, 同时重置校验次数
+const SAVE_SCRIPT: &str = r#"
+redis.call('DEL', KEYS[1])
+redis.call('HSET', KEYS[1], 'code', ARGV[1], 'attempts', 0)
+redis.call('EXPIRE', KEYS[1], ARGV[2])
+"#;
+
+/// 校验验证码, 成功或校验次数用尽后删除. 返回1表示校验成功
+const VERIFY_SCRIPT: &str = r#"
+local code = redis.call('HGET', KEYS[1], 'code')
+if not code then
+    return 0
+end
+local attempts = redis.call('HINCRBY', KEYS[1], 'attempts', 1)
+if code == ARGV[1] then
+    redis.call('DEL', KEYS[1])
+    return 1
+end
+if attempts >= tonumber(ARGV[2]) then
+    redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SendCodeRequest {
+    #[validate(regex(path = "*MOBILE_PHONE_REGEX", message = "Please provide a valid mobile phone"))]
+    pub mobile: String,
+}
+
+/// 短信发送通道
+#[async_trait]
+pub trait SmsSender: Send + Sync {
+    async fn send(&self, mobile: &str, content: &str) -> Result<()>;
+}
+
+/// 将短信写入日志, 用于本地测试
+pub struct LogSmsSender;
+
+#[async_trait]
+impl SmsSender for LogSmsSender {
+    async fn send(&self, mobile: &str, content: &str) -> Result<()> {
+        tracing::info!("sms to {}: {}", mobile, content);
+        Ok(())
+    }
+}
+
+/// 将短信追加到文件, 用于本地测试
+pub struct FileSmsSender {
+    path: PathBuf,
+}
+
+impl FileSmsSender {
+    pub fn new(path: PathBuf) -> Self {
+        FileSmsSender { path }
+    }
+}
+
+#[async_trait]
+impl SmsSender for FileSmsSender {
+    async fn send(&self, mobile: &str, content: &str) -> Result<()> {
+        let line = format!("{} {} {}\n", chrono::Local::now().to_rfc3339(), mobile, content);
+        let res = async {
+            let mut file = tokio::fs::OpenOptions::new().create(true).append(true).open(&self.path).await?;
+            file.write_all(line.as_bytes()).await
+        }
+        .await;
+        res.map_err(|err| {
+            tracing::error!("write sms to {} failed, {err:#}", self.path.display());
+            Error::InternalServerError
+        })
+    }
+}
+
+/// 根据配置选择短信发送通道
+pub fn sms_sender(cfg: &SmsConfig) -> Arc<dyn SmsSender> {
+    match &cfg.outbox_path {
+        Some(path) => Arc::new(FileSmsSender::new(path.clone())),
+        None => Arc::new(LogSmsSender),
+    }
+}
+
+#[async_trait]
+pub trait ISmsCodeService: Interface {
+    /// 生成并发送登录验证码, 返回验证码有效期
+    async fn send_code(&self, mobile: &str, ip: &str) -> Result<Duration>;
+    /// 校验验证码, 验证码只能使用一次
+    async fn verify_code(&self, mobile: &str, code: &str) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = ISmsCodeService)]
+pub struct SmsCodeServiceImpl {
+    #[shaku(no_default)]
+    cfg: Arc<Config>,
+    #[shaku(no_default)]
+    sender: Arc<dyn SmsSender>,
+    #[shaku(inject)]
+    redis_cli: Arc<dyn IRedisService>,
+}
+
+impl SmsCodeServiceImpl {
+    /// 统计窗口内的计数加一, 超过上限时拒绝
+    async fn check_limit(&self, key: String, limit: u32) -> Result<()> {
+        let count: i64 = self
+            .redis_cli
+            .get_conn()
+            .eval(INCR_SCRIPT, key.as_str(), LIMIT_WINDOW)
+            .await
+            .map_err(|err| {
+                tracing::error!("check sms limit {} failed, {err:#}", key);
+                Error::InternalServerError
+            })?;
+        if count > limit as i64 {
+            tracing::warn!("sms limit {} exceeded", key);
+            return Err(Error::TooManyRequests);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ISmsCodeService for SmsCodeServiceImpl {
+    async fn send_code(&self, mobile: &str, ip: &str) -> Result<Duration> {
+        let cfg = &self.cfg.sms;
+        let redis_cli = self.redis_cli.get_conn();
+        let resend_interval = cfg.resend_interval.unwrap_or(DEFAULT_RESEND_INTERVAL);
+        let cooldown: Option<String> = redis_cli
+            .set(
+                format!("{COOLDOWN_KEY_PREFIX}{mobile}"),
+                1,
+                Some(Expiration::EX(resend_interval.as_secs().max(1) as i64)),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("check sms cooldown of {} failed, {err:#}", mobile);
+                Error::InternalServerError
+            })?;
+        if cooldown.is_none() {
+            return Err(Error::TooManyRequests);
+        }
+        self.check_limit(
+            format!("{PHONE_LIMIT_KEY_PREFIX}{mobile}"),
+            cfg.phone_hourly_limit.unwrap_or(DEFAULT_PHONE_HOURLY_LIMIT),
+        )
+        .await?;
+        self.check_limit(
+            format!("{IP_LIMIT_KEY_PREFIX}{ip}"),
+            cfg.ip_hourly_limit.unwrap_or(DEFAULT_IP_HOURLY_LIMIT),
+        )
+        .await?;
+
+        let code = format!("{:06}", OsRng.next_u32() % 1_000_000);
+        let ttl = cfg.code_ttl.unwrap_or(DEFAULT_CODE_TTL);
+        let key = format!("{CODE_KEY_PREFIX}{mobile}");
+        redis_cli
+            .eval::<(), _, _, _>(
+                SAVE_SCRIPT,
+                key.as_str(),
+                vec![code.clone(), ttl.as_secs().to_string()],
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("save sms code of {} failed, {err:#}", mobile);
+                Error::InternalServerError
+            })?;
+
+        let content = format!(
+            "您的登录验证码为{code}, {}分钟内有效, 请勿泄露给他人",
+            ttl.as_secs().div_ceil(60)
+        );
+        if let Err(err) = self.sender.send(mobile, &content).await {
+            let _ = redis_cli.del::<(), _>(key).await;
+            return Err(err);
+        }
+        tracing::info!("send sms code to {} from {}", mobile, ip);
+        Ok(ttl)
+    }
+
+    async fn verify_code(&self, mobile: &str, code: &str) -> Result<()> {
+        let max_attempts = self.cfg.sms.max_attempts.unwrap_or(DEFAULT_MAX_ATTEMPTS).max(1);
+        let valid: i64 = self
+            .redis_cli
+            .get_conn()
+            .eval(
+                VERIFY_SCRIPT,
+                format!("{CODE_KEY_PREFIX}{mobile}"),
+                vec![code.to_string(), max_attempts.to_string()],
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("verify sms code of {} failed, {err:#}", mobile);
+                Error::InternalServerError
+            })?;
+        if valid != 1 {
+            return Err(Error::VerifyCodeInvalid);
+        }
+        Ok(())
+    }
+}
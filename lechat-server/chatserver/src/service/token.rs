@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use fred::prelude::{Expiration, HashesInterface, KeysInterface, RedisResult};
+use fred::types::RedisMap;
+use serde::{Deserialize, Serialize};
+use shaku::{Component, Interface};
+use uuid::Uuid;
+
+use crate::base::config::Config;
+use crate::base::response::{Error, Result};
+use crate::components::redis::IRedisService;
+use crate::service::user::ClientType;
+
+/// 默认访问令牌有效期
+const DEFAULT_ACCESS_TTL: Duration = Duration::from_secs(2 * 3600);
+/// 默认刷新令牌有效期
+const DEFAULT_REFRESH_TTL: Duration = Duration::from_secs(30 * 24 * 3600);
+
+const ACCESS_KEY_PREFIX: &str = "token:access:";
+const REFRESH_KEY_PREFIX: &str = "token:refresh:";
+/// 用户的全部刷新令牌及其数据, 用于吊销
+const USER_TOKENS_KEY_PREFIX: &str = "token:user:";
+
+/// 令牌对应的用户, 认证中间件将其注入请求
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthUser {
+    pub user_id: String,
+    pub client_type: ClientType,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TokenPair {
+    pub access_token: String,
+    pub refresh_token: String,
+    /// 访问令牌有效期(秒)
+    pub expires_in: u64,
+}
+
+/// 刷新令牌对应的数据, 记录同时签发的访问令牌以便一并失效
+#[derive(Debug, Serialize, Deserialize)]
+struct RefreshData {
+    user: AuthUser,
+    access_token: String,
+}
+
+#[async_trait]
+pub trait ITokenService: Interface {
+    /// 登录成功后签发访问令牌及刷新令牌
+    async fn issue(&self, user: &AuthUser) -> Result<TokenPair>;
+    /// 校验访问令牌
+    async fn authenticate(&self, access_token: &str) -> Result<AuthUser>;
+    /// 使用刷新令牌换取新的令牌, 旧的令牌同时失效
+    async fn refresh(&self, refresh_token: &str) -> Result<TokenPair>;
+    /// 吊销用户指定设备类型上的令牌, 不指定时吊销全部设备
+    async fn revoke(&self, user_id: &str, client_type: Option<ClientType>) -> Result<()>;
+    /// 吊销用户除指定设备类型外的全部令牌
+    async fn revoke_except(&self, user_id: &str, client_type: ClientType) -> Result<()>;
+}
+
+#[derive(Component)]
+#[shaku(interface = ITokenService)]
+pub struct TokenServiceImpl {
+    #[shaku(no_default)]
+    cfg: Arc<Config>,
+    #[shaku(inject)]
+    redis_cli: Arc<dyn IRedisService>,
+}
+
+impl TokenServiceImpl {
+    fn access_ttl(&self) -> Duration {
+        self.cfg.token.access_ttl.unwrap_or(DEFAULT_ACCESS_TTL)
+    }
+
+    fn refresh_ttl(&self) -> Duration {
+        self.cfg.token.refresh_ttl.unwrap_or(DEFAULT_REFRESH_TTL)
+    }
+
+    /// 吊销设备类型满足条件的令牌, 无法解析的数据一并清理
+    async fn revoke_matching(
+        &self,
+        user_id: &str,
+        matches: impl Fn(ClientType) -> bool + Send,
+    ) -> Result<()> {
+        let redis_cli = self.redis_cli.get_conn();
+        let user_key = format!("{USER_TOKENS_KEY_PREFIX}{user_id}");
+        let tokens: HashMap<String, String> = redis_cli.hgetall(&user_key).await.map_err(|err| {
+            tracing::error!("load tokens of user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+
+        let mut keys = Vec::new();
+        let mut fields = Vec::new();
+        for (refresh_token, data) in tokens {
+            let data = serde_json::from_str::<RefreshData>(&data).ok();
+            if data.as_ref().is_some_and(|data| !matches(data.user.client_type)) {
+                continue;
+            }
+            if let Some(data) = data {
+                keys.push(format!("{ACCESS_KEY_PREFIX}{}", data.access_token));
+            }
+            keys.push(format!("{REFRESH_KEY_PREFIX}{refresh_token}"));
+            fields.push(refresh_token);
+        }
+        if fields.is_empty() {
+            return Ok(());
+        }
+
+        let count = fields.len();
+        let res: RedisResult<()> = async {
+            redis_cli.del::<(), _>(keys).await?;
+            redis_cli.hdel::<(), _, _>(&user_key, fields).await
+        }
+        .await;
+        res.map_err(|err| {
+            tracing::error!("revoke tokens of user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+        tracing::info!("revoke {} tokens of user {}", count, user_id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ITokenService for TokenServiceImpl {
+    async fn issue(&self, user: &AuthUser) -> Result<TokenPair> {
+        let access_token = new_token();
+        let refresh_token = new_token();
+        let access_ttl = self.access_ttl();
+        let refresh_ttl = self.refresh_ttl().as_secs() as i64;
+        let refresh_data = RefreshData {
+            user: user.clone(),
+            access_token: access_token.clone(),
+        };
+        let (user_data, refresh_data) =
+            match (serde_json::to_string(user), serde_json::to_string(&refresh_data)) {
+                (Ok(user_data), Ok(refresh_data)) => (user_data, refresh_data),
+                (Err(err), _) | (_, Err(err)) => {
+                    tracing::error!("serialize token data failed, {err:#}");
+                    return Err(Error::InternalServerError);
+                }
+            };
+
+        let redis_cli = self.redis_cli.get_conn();
+        let user_key = format!("{USER_TOKENS_KEY_PREFIX}{}", user.user_id);
+        let res: RedisResult<()> = async {
+            redis_cli
+                .set::<(), _, _>(
+                    format!("{ACCESS_KEY_PREFIX}{access_token}"),
+                    user_data,
+                    Some(Expiration::EX(access_ttl.as_secs() as i64)),
+                    None,
+                    false,
+                )
+                .await?;
+            redis_cli
+                .set::<(), _, _>(
+                    format!("{REFRESH_KEY_PREFIX}{refresh_token}"),
+                    refresh_data.as_str(),
+                    Some(Expiration::EX(refresh_ttl)),
+                    None,
+                    false,
+                )
+                .await?;
+            redis_cli
+                .hset::<(), _, _>(
+                    &user_key,
+                    RedisMap::try_from((refresh_token.as_str(), refresh_data.as_str()))?,
+                )
+                .await?;
+            redis_cli.expire::<(), _>(&user_key, refresh_ttl).await
+        }
+        .await;
+        res.map_err(|err| {
+            tracing::error!("issue token for user {} failed, {err:#}", user.user_id);
+            Error::InternalServerError
+        })?;
+
+        Ok(TokenPair {
+            access_token,
+            refresh_token,
+            expires_in: access_ttl.as_secs(),
+        })
+    }
+
+    async fn authenticate(&self, access_token: &str) -> Result<AuthUser> {
+        let data: Option<String> = self
+            .redis_cli
+            .get_conn()
+            .get(format!("{ACCESS_KEY_PREFIX}{access_token}"))
+            .await
+            .map_err(|err| {
+                tracing::error!("load access token failed, {err:#}");
+                Error::InternalServerError
+            })?;
+        let data = data.ok_or(Error::TokenInvalid)?;
+        serde_json::from_str(&data).map_err(|err| {
+            tracing::error!("deserialize access token failed, {err:#}");
+            Error::TokenInvalid
+        })
+    }
+
+    async fn refresh(&self, refresh_token: &str) -> Result<TokenPair> {
+        let redis_cli = self.redis_cli.get_conn();
+        // 取出即删除, 刷新令牌只能使用一次
+        let data: Option<String> = redis_cli
+            .getdel(format!("{REFRESH_KEY_PREFIX}{refresh_token}"))
+            .await
+            .map_err(|err| {
+                tracing::error!("load refresh token failed, {err:#}");
+                Error::InternalServerError
+            })?;
+        let data = data.ok_or(Error::TokenInvalid)?;
+        let data: RefreshData = serde_json::from_str(&data).map_err(|err| {
+            tracing::error!("deserialize refresh token failed, {err:#}");
+            Error::TokenInvalid
+        })?;
+
+        let user_key = format!("{USER_TOKENS_KEY_PREFIX}{}", data.user.user_id);
+        let res: RedisResult<()> = async {
+            redis_cli
+                .del::<(), _>(format!("{ACCESS_KEY_PREFIX}{}", data.access_token))
+                .await?;
+            redis_cli.hdel::<(), _, _>(&user_key, refresh_token).await
+        }
+        .await;
+        if let Err(err) = res {
+            tracing::error!("revoke token of user {} failed, {err:#}", data.user.user_id);
+        }
+        self.issue(&data.user).await
+    }
+
+    async fn revoke(&self, user_id: &str, client_type: Option<ClientType>) -> Result<()> {
+        self.revoke_matching(user_id, |v| client_type.is_none_or(|c| c == v)).await
+    }
+
+    async fn revoke_except(&self, user_id: &str, client_type: ClientType) -> Result<()> {
+        self.revoke_matching(user_id, |v| v != client_type).await
+    }
+}
+
+fn new_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
@@ -1,28 +1,52 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
+use library::utils;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use sea_orm::ActiveValue::Set;
 use sea_orm::NotSet;
 use serde::{Deserialize, Serialize};
-use shaku::{Component, HasComponent, Interface};
-use validator::Validate;
+use shaku::{Component, Interface};
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
 
 use crate::base::response::{Error, Result};
+use crate::db::entity::sea_orm_active_enums::UserStatus;
 use crate::db::entity::user as entity;
-use crate::db::repository::user::IUserRepository;
-use crate::service::checker::ICheckService;
-
-const MOBILE_PHONE_PATTERN: &str =
-    "/^1(3[0-9]|4[01456879]|5[0-35-9]|6[2567]|7[0-8]|8[0-9]|9[0-35-9])\\d{8}$/";
-static MOBILE_PHONE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(MOBILE_PHONE_PATTERN).unwrap());
-/// 至少8个字符，至少包含一个字母（大写或小写）、数字或者特殊字符
-const PASSWORD_PATTERN: &str = r"^(?=.*[A-Za-z])(?=.*\d)[A-Za-z\d@$!%*#?&]{8,}$";
+use crate::db::repository::user::{IUserRepository, PublicUser, UserSearch};
+use crate::network::registry::ISessionRegistry;
+use crate::network::stubs::chatmsg;
+use crate::service::audit::{AuditAction, AuditSource, IAuditService};
+use crate::service::login_guard::ILoginGuardService;
+use crate::service::password::{IPasswordService, Verification};
+use crate::service::presence::IPresenceService;
+use crate::service::reservation::{IReservationService, Reservation, ReserveKind};
+use crate::service::sms::ISmsCodeService;
+use crate::service::token::{AuthUser, ITokenService, TokenPair};
+use crate::service::verification::IVerificationService;
+
+const MOBILE_PHONE_PATTERN: &str = "^1(3[0-9]|4[01456879]|5[0-35-9]|6[2567]|7[0-8]|8[0-9]|9[0-35-9])\\d{8}$";
+pub(crate) static MOBILE_PHONE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(MOBILE_PHONE_PATTERN).unwrap());
+/// 至少8个字符，至少包含一个字母（大写或小写）、数字或者特殊字符.
+/// regex不支持前瞻断言, 字母及数字的要求在`validate_password`中检查
+const PASSWORD_PATTERN: &str = r"^[A-Za-z\d@$!%*#?&]{8,}$";
 static PASSWORD_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(PASSWORD_PATTERN).unwrap());
+const PASSWORD_MESSAGE: &str = "至少8个字符，其中至少包含一个字母（大写或小写）、数字或者特殊字符";
 
-const USER_KEY: &str = "username";
+/// 密码策略校验
+pub(crate) fn validate_password(password: &str) -> std::result::Result<(), ValidationError> {
+    let valid = PASSWORD_REGEX.is_match(password)
+        && password.chars().any(|c| c.is_ascii_alphabetic())
+        && password.chars().any(|c| c.is_ascii_digit());
+    if valid {
+        return Ok(());
+    }
+    let mut err = ValidationError::new("password");
+    err.message = Some(PASSWORD_MESSAGE.into());
+    Err(err)
+}
 
 #[repr(u8)]
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -59,10 +83,7 @@ pub struct SignUpRequest {
     pub username: String,
     #[validate(length(min = 1, max = 20, message = "昵称至少6个字符，最多20个字符"))]
     pub nickname: String,
-    #[validate(regex(
-        path = "*PASSWORD_REGEX",
-        message = "至少8个字符，其中至少包含一个字母（大写或小写）、数字或者特殊字符"
-    ))]
+    #[validate(custom(function = "validate_password"))]
     pub password: String,
     #[validate(regex(path = "*MOBILE_PHONE_REGEX", message = "Please provide a valid mobile phone"))]
     pub mobile: String,
@@ -71,7 +92,7 @@ pub struct SignUpRequest {
     pub gender: Gender,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ClientType {
     WINDOWS = 1,
     LINUX = 2,
@@ -81,7 +102,7 @@ pub enum ClientType {
     IPAD = 6,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OnlineStatus {
     OFFLINE = 0,
     INVISIBLE = 1,
@@ -101,6 +122,124 @@ pub struct SignInRequest {
     pub online_status: OnlineStatus,
 }
 
+#[derive(Debug, Deserialize, Validate)]
+pub struct MobileSignInRequest {
+    #[validate(regex(path = "*MOBILE_PHONE_REGEX", message = "Please provide a valid mobile phone"))]
+    pub mobile: String,
+    #[validate(length(equal = 6, message = "请提供一个合法的验证码!"))]
+    pub code: String,
+    pub client_type: ClientType,
+    pub online_status: OnlineStatus,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ModifyPasswordRequest {
+    #[validate(length(min = 1, message = "原密码不能为空"))]
+    pub old_password: String,
+    #[validate(custom(function = "validate_password"))]
+    pub new_password: String,
+}
+
+/// 修改资料, 只修改设置了的字段
+#[derive(Debug, Default, Deserialize, Validate)]
+pub struct UpdateUserInfoRequest {
+    #[validate(length(min = 1, max = 20, message = "昵称至少1个字符，最多20个字符"))]
+    pub nickname: Option<String>,
+    pub gender: Option<Gender>,
+    /// yyyymmdd, 如19900101
+    #[validate(custom(function = "validate_birthday"))]
+    pub birthday: Option<i64>,
+    #[validate(length(max = 64, message = "个性签名最多64个字符"))]
+    pub signature: Option<String>,
+    #[validate(length(max = 256, message = "地址最多256个字符"))]
+    pub address: Option<String>,
+    #[validate(email(message = "Please provide a valid email!"))]
+    pub email: Option<String>,
+    #[validate(range(min = 0, message = "头像编号不合法"))]
+    pub facetype: Option<i32>,
+    #[validate(length(max = 32, message = "自定义头像名最多32个字符"))]
+    pub customface: Option<String>,
+    /// 是否允许其他用户通过手机号搜索到自己
+    pub phone_searchable: Option<bool>,
+}
+
+impl UpdateUserInfoRequest {
+    fn is_empty(&self) -> bool {
+        self.nickname.is_none()
+            && self.gender.is_none()
+            && self.birthday.is_none()
+            && self.signature.is_none()
+            && self.address.is_none()
+            && self.email.is_none()
+            && self.facetype.is_none()
+            && self.customface.is_none()
+            && self.phone_searchable.is_none()
+    }
+}
+
+/// 生日须是不晚于今天的合法日期
+fn validate_birthday(birthday: i64) -> std::result::Result<(), ValidationError> {
+    let date = NaiveDate::parse_from_str(&birthday.to_string(), "%Y%m%d").ok();
+    if date.is_some_and(|date| date <= chrono::Local::now().date_naive()) {
+        return Ok(());
+    }
+    let mut err = ValidationError::new("birthday");
+    err.message = Some("生日不合法".into());
+    Err(err)
+}
+
+/// 搜索方式
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchType {
+    UserId,
+    Username,
+    Phone,
+    Nickname,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SearchUserRequest {
+    #[serde(rename = "type")]
+    pub search_type: SearchType,
+    #[validate(length(min = 1, max = 64, message = "搜索关键字至少1个字符，最多64个字符"))]
+    pub keyword: String,
+    /// 页码, 从1开始
+    #[serde(default = "default_page")]
+    #[validate(range(min = 1, message = "页码从1开始"))]
+    pub page: u64,
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1, max = 50, message = "每页最多50条"))]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+
+fn default_page_size() -> u64 {
+    20
+}
+
+/// 用户公开资料, 不包含密码、手机号等隐私信息
+#[derive(Debug, Serialize)]
+pub struct PublicProfile {
+    pub user_id: String,
+    pub user_name: String,
+    pub nick_name: String,
+    pub gender: Gender,
+    pub signature: Option<String>,
+    pub face_type: Option<i32>,
+    pub custom_face: Option<String>,
+    pub custom_face_fmt: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchUserResult {
+    pub total: u64,
+    pub users: Vec<PublicProfile>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct UserBaseInfo {
     pub user_id: String,
@@ -119,7 +258,8 @@ pub struct UserDetailInfo {
     pub face_type: Option<i32>,
     pub custom_face: Option<String>,
     pub custom_face_fmt: Option<String>,
-    pub group_info: Option<Vec<u8>>,
+    pub phone_searchable: bool,
+    pub status: UserStatus,
     pub register_time: NaiveDateTime,
 }
 
@@ -135,11 +275,33 @@ pub struct UserInfo {
     pub detail_info: UserDetailInfo,
 }
 
+#[derive(Debug, Serialize)]
+pub struct SignInInfo {
+    pub user: UserInfo,
+    pub token: TokenPair,
+}
+
 #[async_trait]
 pub trait IUserService: Interface {
+    /// 注册后账号处于待验证状态, 同时发送验证邮件
     async fn sign_up(&self, register_req: SignUpRequest) -> Result<UserInfo>;
-    async fn sign_in(&self, login_req: SignInRequest) -> Result<()>;
-    async fn sign_out(&self, user_id: &str) -> Result<()>;
+    /// 登录成功后签发访问令牌及刷新令牌, 连续失败过多时暂时锁定, 未验证的账号不能登录
+    async fn sign_in(&self, login_req: SignInRequest, ip: &str) -> Result<SignInInfo>;
+    /// 手机号加短信验证码登录, 待验证的账号随之激活
+    async fn sign_in_by_mobile(&self, login_req: MobileSignInRequest) -> Result<SignInInfo>;
+    /// 校验原密码后修改密码, 其他设备上的登录随之失效
+    async fn modify_password(
+        &self,
+        user: &AuthUser,
+        modify_req: ModifyPasswordRequest,
+        ip: Option<String>,
+    ) -> Result<()>;
+    /// 分页搜索用户, 只返回公开资料
+    async fn search_users(&self, search_req: SearchUserRequest) -> Result<SearchUserResult>;
+    /// 修改资料, 成功后通知在线好友
+    async fn update_user_info(&self, user_id: &str, update_req: UpdateUserInfoRequest) -> Result<UserInfo>;
+    /// 吊销令牌并断开长连接, 不指定设备类型时退出全部设备
+    async fn sign_out(&self, user_id: &str, client_type: Option<ClientType>) -> Result<()>;
 }
 
 #[derive(Component)]
@@ -147,34 +309,102 @@ pub trait IUserService: Interface {
 pub struct UserServiceImpl {
     #[shaku(inject)]
     pub repo: Arc<dyn IUserRepository>,
+    #[shaku(inject)]
+    pub password: Arc<dyn IPasswordService>,
+    #[shaku(inject)]
+    pub token: Arc<dyn ITokenService>,
+    #[shaku(inject)]
+    pub sms_code: Arc<dyn ISmsCodeService>,
+    #[shaku(inject)]
+    pub reservation: Arc<dyn IReservationService>,
+    #[shaku(inject)]
+    pub session_registry: Arc<dyn ISessionRegistry>,
+    #[shaku(inject)]
+    pub presence: Arc<dyn IPresenceService>,
+    #[shaku(inject)]
+    pub audit: Arc<dyn IAuditService>,
+    #[shaku(inject)]
+    pub login_guard: Arc<dyn ILoginGuardService>,
+    #[shaku(inject)]
+    pub verification: Arc<dyn IVerificationService>,
+}
+
+impl UserServiceImpl {
+    /// 预占注册用的全部标识并确认数据库中不存在, 失败时回滚已预占的标识
+    async fn reserve(&self, signup_req: &SignUpRequest) -> Result<Vec<Reservation>> {
+        let email = signup_req.email.to_lowercase();
+        let fields = [
+            (ReserveKind::Username, signup_req.username.as_str()),
+            (ReserveKind::Phone, signup_req.mobile.as_str()),
+            (ReserveKind::Email, email.as_str()),
+        ];
+        let mut reservations = Vec::with_capacity(fields.len());
+        for (kind, value) in fields {
+            let res = match self.reservation.reserve(kind, value).await {
+                Ok(Some(reservation)) => {
+                    reservations.push(reservation);
+                    self.exists(kind, value).await
+                }
+                Ok(None) => Ok(true),
+                Err(err) => Err(err),
+            };
+            let err = match res {
+                Ok(false) => continue,
+                Ok(true) => kind.duplicate_error(),
+                Err(err) => err,
+            };
+            self.reservation.rollback(reservations).await;
+            return Err(err);
+        }
+        Ok(reservations)
+    }
+
+    /// 数据库中是否已存在该标识
+    async fn exists(&self, kind: ReserveKind, value: &str) -> Result<bool> {
+        let user = match kind {
+            ReserveKind::Username => self.repo.find_by_name(value).await,
+            ReserveKind::Phone => self.repo.find_by_phone(value).await,
+            ReserveKind::Email => self.repo.find_by_email(value).await,
+        };
+        user.map(|u| u.is_some()).map_err(|err| {
+            tracing::error!("check {:?} {} failed, {err:#}", kind, value);
+            Error::InternalServerError
+        })
+    }
 }
 
 #[async_trait]
 impl IUserService for UserServiceImpl {
-    async fn sign_up(&self, signup_req: SignUpRequest) -> Result<UserInfo> {
-        let modules = super::service_factory()?;
-        let check_service: &dyn ICheckService = modules.resolve_ref();
-        let duplicate = check_service.is_duplicate(USER_KEY, &signup_req.username).await;
-        match duplicate {
-            Ok(v) => {
-                if v {
-                    return Err(Error::UsernameDuplicate);
+    async fn sign_up(&self, mut signup_req: SignUpRequest) -> Result<UserInfo> {
+        // 预占用户名、手机号及邮箱, 防止并发注册时重复
+        let reservations = self.reserve(&signup_req).await?;
+        let res = async {
+            signup_req.password = self.password.hash(&signup_req.password)?;
+            self.repo.add(signup_req.into()).await.map_err(|err| {
+                tracing::error!("sign_up failed, {err:#}");
+                Error::InternalServerError
+            })
+        }
+        .await;
+        match res {
+            Ok(model) => {
+                self.reservation.confirm(reservations).await;
+                // 发送失败时仍可通过短信验证码验证
+                if let Err(err) = self.verification.send_email_link(&model).await {
+                    tracing::error!("send verification mail to user {} failed, {err:#}", model.user_id);
                 }
+                Ok(model.into())
             }
             Err(err) => {
-                tracing::error!("check username failed, {err:#}");
+                self.reservation.rollback(reservations).await;
+                Err(err)
             }
         }
-
-        // todo 密码需要加密处理
-        let model = self.repo.add(signup_req.into()).await.map_err(|err| {
-            tracing::error!("sign_up failed, {err:#}");
-            Error::InternalServerError
-        })?;
-        Ok(model.into())
     }
 
-    async fn sign_in(&self, signin_req: SignInRequest) -> Result<()> {
+    async fn sign_in(&self, signin_req: SignInRequest, ip: &str) -> Result<SignInInfo> {
+        // 连续失败过多的用户名或IP暂时禁止登录
+        self.login_guard.check(&signin_req.username, ip).await?;
         // 校验用户是否注册
         let user = self.repo.find_by_name(&signin_req.username).await.map_err(|err| {
             tracing::error!("sign_in failed, {err:#}");
@@ -183,27 +413,265 @@ impl IUserService for UserServiceImpl {
 
         let Some(u) = user else {
             // 账号未注册
+            self.login_guard.record_failure(&signin_req.username, ip).await;
             return Err(Error::UserNotRegistered);
         };
         // 校验用户名、密码是否正确
-        let mut valid = false;
-        if u.user_name.eq(&signin_req.username) {
-            valid = true;
+        if !u.user_name.eq(&signin_req.username) {
+            self.login_guard.record_failure(&signin_req.username, ip).await;
+            return Err(Error::UserNameOrPasswordMismatch);
         }
-        // todo 校验密码，密码需要解密
-        if let Some(pwd) = u.password {
-            if pwd.eq(&signin_req.password) {
-                valid = valid & true;
+        let stored = u.password.as_deref().unwrap_or_default();
+        match self.password.verify(&signin_req.password, stored)? {
+            Verification::Mismatch => {
+                self.login_guard.record_failure(&signin_req.username, ip).await;
+                return Err(Error::UserNameOrPasswordMismatch);
             }
+            Verification::Valid => {}
+            Verification::Outdated => {
+                // 明文或参数较弱的哈希, 使用当前参数重新哈希
+                let mut model: entity::ActiveModel = u.clone().into();
+                model.password = Set(Some(self.password.hash(&signin_req.password)?));
+                if let Err(err) = self.repo.update(model).await {
+                    tracing::error!("rehash password of user {} failed, {err:#}", u.user_id);
+                }
+            }
+        }
+        self.login_guard.reset(&signin_req.username).await;
+        if u.status == UserStatus::PendingVerification {
+            return Err(Error::AccountNotVerified);
         }
+        let token = self
+            .token
+            .issue(&AuthUser {
+                user_id: u.user_id.clone(),
+                client_type: signin_req.client_type,
+            })
+            .await?;
+        Ok(SignInInfo {
+            user: u.into(),
+            token,
+        })
+    }
 
-        if !valid {
-            return Err(Error::UserNameOrPasswordMismatch);
+    async fn sign_in_by_mobile(&self, signin_req: MobileSignInRequest) -> Result<SignInInfo> {
+        self.sms_code.verify_code(&signin_req.mobile, &signin_req.code).await?;
+        let user = self.repo.find_by_phone(&signin_req.mobile).await.map_err(|err| {
+            tracing::error!("sign_in_by_mobile failed, {err:#}");
+            Error::InternalServerError
+        })?;
+        let Some(mut u) = user else {
+            // 手机号未注册
+            return Err(Error::UserNotRegistered);
+        };
+        // 验证码已经证明手机号归属, 顺带激活待验证的账号
+        if u.status == UserStatus::PendingVerification {
+            self.repo.activate(&u.user_id).await.map_err(|err| {
+                tracing::error!("activate user {} failed, {err:#}", u.user_id);
+                Error::InternalServerError
+            })?;
+            u.status = UserStatus::Active;
         }
-        return Ok(());
+        let token = self
+            .token
+            .issue(&AuthUser {
+                user_id: u.user_id.clone(),
+                client_type: signin_req.client_type,
+            })
+            .await?;
+        Ok(SignInInfo {
+            user: u.into(),
+            token,
+        })
     }
 
-    async fn sign_out(&self, _user_id: &str) -> Result<()> {
+    async fn modify_password(
+        &self,
+        user: &AuthUser,
+        modify_req: ModifyPasswordRequest,
+        ip: Option<String>,
+    ) -> Result<()> {
+        if modify_req.old_password == modify_req.new_password {
+            return Err(Error::ParamInvalid("新密码不能与原密码相同".to_string()));
+        }
+        let u = self
+            .repo
+            .find_by_user_id(&user.user_id)
+            .await
+            .map_err(|err| {
+                tracing::error!("modify_password failed, {err:#}");
+                Error::InternalServerError
+            })?
+            .ok_or(Error::UserNotRegistered)?;
+        let stored = u.password.as_deref().unwrap_or_default();
+        if self.password.verify(&modify_req.old_password, stored)? == Verification::Mismatch {
+            return Err(Error::PasswordMismatch);
+        }
+
+        let user_pk = u.id;
+        let mut model: entity::ActiveModel = u.into();
+        model.password = Set(Some(self.password.hash(&modify_req.new_password)?));
+        self.repo.update(model).await.map_err(|err| {
+            tracing::error!("update password of user {} failed, {err:#}", user.user_id);
+            Error::InternalServerError
+        })?;
+        self.audit
+            .record(
+                user_pk,
+                AuditAction::ModifyPassword,
+                AuditSource {
+                    client_type: Some(user.client_type),
+                    ip,
+                },
+            )
+            .await;
+
+        // 当前设备保持登录, 其他设备的令牌及长连接失效
+        self.token.revoke_except(&user.user_id, user.client_type).await?;
+        let current: chatmsg::ClientType = user.client_type.into();
+        for client_type in self.session_registry.online_clients(&user.user_id).await? {
+            if client_type == current {
+                continue;
+            }
+            let kicked = self
+                .session_registry
+                .kick(&user.user_id, Some(client_type), "密码已修改, 请重新登录")
+                .await?;
+            for client_type in kicked {
+                if let Err(err) = self
+                    .presence
+                    .notify_status(&user.user_id, client_type, chatmsg::OnlineStatus::OFFLINE)
+                    .await
+                {
+                    tracing::error!("notify offline of user {} failed, {err:#}", user.user_id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn search_users(&self, search_req: SearchUserRequest) -> Result<SearchUserResult> {
+        let keyword = search_req.keyword;
+        let cond = match search_req.search_type {
+            SearchType::UserId => UserSearch::UserId(keyword),
+            SearchType::Username => UserSearch::Username(keyword),
+            SearchType::Phone => UserSearch::Phone(keyword),
+            SearchType::Nickname => UserSearch::NicknamePrefix(keyword),
+        };
+        let (users, total) = self
+            .repo
+            .search(cond, search_req.page.saturating_sub(1), search_req.page_size)
+            .await
+            .map_err(|err| {
+                tracing::error!("search users failed, {err:#}");
+                Error::InternalServerError
+            })?;
+        Ok(SearchUserResult {
+            total,
+            users: users.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    async fn update_user_info(&self, user_id: &str, update_req: UpdateUserInfoRequest) -> Result<UserInfo> {
+        if update_req.is_empty() {
+            return Err(Error::ParamInvalid("没有需要修改的资料".to_string()));
+        }
+        let u = self
+            .repo
+            .find_by_user_id(user_id)
+            .await
+            .map_err(|err| {
+                tracing::error!("update_user_info failed, {err:#}");
+                Error::InternalServerError
+            })?
+            .ok_or(Error::UserNotRegistered)?;
+
+        // 更换邮箱时与注册一样预占, 防止与其他用户重复
+        let mut reservations = Vec::new();
+        if let Some(email) = update_req.email.as_deref() {
+            let email = email.to_lowercase();
+            if u.email.as_deref().map(str::to_lowercase).as_deref() != Some(email.as_str()) {
+                let Some(reservation) = self.reservation.reserve(ReserveKind::Email, &email).await? else {
+                    return Err(Error::EmailDuplicate);
+                };
+                reservations.push(reservation);
+                let exists = match self.exists(ReserveKind::Email, &email).await {
+                    Ok(exists) => exists,
+                    Err(err) => {
+                        self.reservation.rollback(reservations).await;
+                        return Err(err);
+                    }
+                };
+                if exists {
+                    self.reservation.rollback(reservations).await;
+                    return Err(Error::EmailDuplicate);
+                }
+            }
+        }
+
+        let mut model: entity::ActiveModel = u.into();
+        if let Some(nickname) = update_req.nickname {
+            model.nick_name = Set(nickname);
+        }
+        if let Some(gender) = &update_req.gender {
+            model.gender = Set(gender.into());
+        }
+        if let Some(birthday) = update_req.birthday {
+            model.birthday = Set(Some(birthday));
+        }
+        if let Some(signature) = update_req.signature {
+            model.signature = Set(Some(signature));
+        }
+        if let Some(address) = update_req.address {
+            model.address = Set(Some(address));
+        }
+        if let Some(email) = update_req.email {
+            model.email = Set(Some(email));
+        }
+        if let Some(facetype) = update_req.facetype {
+            model.facetype = Set(Some(facetype));
+        }
+        if let Some(customface) = update_req.customface {
+            model.customface = Set(Some(customface));
+        }
+        if let Some(phone_searchable) = update_req.phone_searchable {
+            model.phone_searchable = Set(phone_searchable);
+        }
+        let model = match self.repo.update(model).await {
+            Ok(model) => {
+                self.reservation.confirm(reservations).await;
+                model
+            }
+            Err(err) => {
+                tracing::error!("update user info of {} failed, {err:#}", user_id);
+                self.reservation.rollback(reservations).await;
+                return Err(Error::InternalServerError);
+            }
+        };
+
+        let info: UserInfo = model.into();
+        if let Err(err) = self.presence.notify_profile(&info).await {
+            tracing::error!("notify profile of user {} failed, {err:#}", user_id);
+        }
+        Ok(info)
+    }
+
+    async fn sign_out(&self, user_id: &str, client_type: Option<ClientType>) -> Result<()> {
+        self.token.revoke(user_id, client_type).await?;
+        let kicked = self
+            .session_registry
+            .kick(user_id, client_type.map(Into::into), "已退出登录")
+            .await?;
+        for client_type in kicked {
+            if let Err(err) = self
+                .presence
+                .notify_status(user_id, client_type, chatmsg::OnlineStatus::OFFLINE)
+                .await
+            {
+                tracing::error!("notify offline of user {} failed, {err:#}", user_id);
+            }
+        }
+        tracing::info!("user {} signed out from {:?}", user_id, client_type);
         Ok(())
     }
 }
@@ -244,7 +712,7 @@ impl Into<entity::ActiveModel> for SignUpRequest {
     fn into(self) -> entity::ActiveModel {
         entity::ActiveModel {
             id: NotSet,
-            user_id: Default::default(),
+            user_id: Set(Uuid::new_v4().simple().to_string()),
             user_name: Set(self.username),
             nick_name: Set(self.nickname),
             password: Set(Some(self.password)),
@@ -257,8 +725,39 @@ impl Into<entity::ActiveModel> for SignUpRequest {
             facetype: Default::default(),
             customface: Default::default(),
             customfacefmt: Default::default(),
-            gropup_info: Default::default(),
-            register_time: Default::default(),
+            phone_searchable: Default::default(),
+            status: Set(UserStatus::PendingVerification),
+            register_time: Set(utils::time::now_naive_datetime()),
+        }
+    }
+}
+
+impl From<PublicUser> for PublicProfile {
+    fn from(value: PublicUser) -> Self {
+        PublicProfile {
+            user_id: value.user_id,
+            user_name: value.user_name,
+            nick_name: value.nick_name,
+            gender: Gender::from(value.gender),
+            signature: value.signature,
+            face_type: value.facetype,
+            custom_face: value.customface,
+            custom_face_fmt: value.customfacefmt,
+        }
+    }
+}
+
+impl From<&entity::Model> for PublicProfile {
+    fn from(value: &entity::Model) -> Self {
+        PublicProfile {
+            user_id: value.user_id.clone(),
+            user_name: value.user_name.clone(),
+            nick_name: value.nick_name.clone(),
+            gender: Gender::from(value.gender),
+            signature: value.signature.clone(),
+            face_type: value.facetype,
+            custom_face: value.customface.clone(),
+            custom_face_fmt: value.customfacefmt.clone(),
         }
     }
 }
@@ -281,7 +780,8 @@ impl From<entity::Model> for UserInfo {
                 face_type: value.facetype,
                 custom_face: value.customface,
                 custom_face_fmt: value.customfacefmt,
-                group_info: value.gropup_info,
+                phone_searchable: value.phone_searchable,
+                status: value.status,
                 register_time: value.register_time,
             },
         }
@@ -322,3 +822,41 @@ impl From<entity::Model> for UserInfo {
 //         }
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn password_accepts_letters_digits_and_symbols() {
+        assert!(validate_password("abcd1234").is_ok());
+        assert!(validate_password("Abc@1234!").is_ok());
+    }
+
+    #[test]
+    fn password_rejects_letters_only() {
+        assert!(validate_password("abcdefgh").is_err());
+    }
+
+    #[test]
+    fn password_rejects_digits_only() {
+        assert!(validate_password("12345678").is_err());
+    }
+
+    #[test]
+    fn password_rejects_shorter_than_8() {
+        assert!(validate_password("abc1234").is_err());
+    }
+
+    #[test]
+    fn password_rejects_disallowed_symbol() {
+        assert!(validate_password("abcd1234^").is_err());
+        assert!(validate_password("abcd 1234").is_err());
+    }
+
+    #[test]
+    fn password_error_carries_policy_message() {
+        let err = validate_password("short").unwrap_err();
+        assert_eq!(err.message.as_deref(), Some(PASSWORD_MESSAGE));
+    }
+}
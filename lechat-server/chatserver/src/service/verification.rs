@@ -0,0 +1,193 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use fred::prelude::{Expiration, KeysInterface};
+use library::utils;
+use serde::Deserialize;
+use shaku::{Component, Interface};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::base::config::Config;
+use crate::base::response::{Error, Result};
+use crate::components::redis::IRedisService;
+use crate::db::entity::sea_orm_active_enums::UserStatus;
+use crate::db::entity::user::Model;
+use crate::db::repository::user::IUserRepository;
+use crate::service::hash_token;
+use crate::service::mail::Mailer;
+use crate::service::sms::ISmsCodeService;
+use crate::service::user::MOBILE_PHONE_REGEX;
+
+/// 默认未验证账号的保留时长
+const DEFAULT_TTL: Duration = Duration::from_secs(24 * 3600);
+/// 默认清理未验证账号的间隔
+const DEFAULT_CLEANUP_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// 验证令牌摘要到用户ID
+const EMAIL_TOKEN_KEY_PREFIX: &str = "verify:email:";
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1, message = "验证令牌不能为空"))]
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct VerifyMobileRequest {
+    #[validate(regex(path = "*MOBILE_PHONE_REGEX", message = "Please provide a valid mobile phone"))]
+    pub mobile: String,
+    #[validate(length(equal = 6, message = "请提供一个合法的验证码!"))]
+    pub code: String,
+}
+
+#[async_trait]
+pub trait IVerificationService: Interface {
+    /// 向注册邮箱发送验证链接
+    async fn send_email_link(&self, user: &Model) -> Result<()>;
+    /// 通过邮件中的令牌激活账号, 令牌只能使用一次
+    async fn verify_email(&self, token: &str) -> Result<()>;
+    /// 通过短信验证码激活手机号对应的账号
+    async fn verify_mobile(&self, mobile: &str, code: &str) -> Result<()>;
+    /// 删除超时未验证的账号, 返回删除的数量
+    async fn purge_unverified(&self) -> Result<u64>;
+}
+
+#[derive(Component)]
+#[shaku(interface = IVerificationService)]
+pub struct VerificationServiceImpl {
+    #[shaku(no_default)]
+    cfg: Arc<Config>,
+    #[shaku(no_default)]
+    mailer: Arc<dyn Mailer>,
+    #[shaku(inject)]
+    redis_cli: Arc<dyn IRedisService>,
+    #[shaku(inject)]
+    repo: Arc<dyn IUserRepository>,
+    #[shaku(inject)]
+    sms_code: Arc<dyn ISmsCodeService>,
+}
+
+impl VerificationServiceImpl {
+    fn ttl(&self) -> Duration {
+        self.cfg.verification.ttl.unwrap_or(DEFAULT_TTL)
+    }
+
+    fn verify_link(&self, token: &str) -> String {
+        match self.cfg.verification.verify_url.as_deref() {
+            Some(url) if url.contains('?') => format!("{url}&token={token}"),
+            Some(url) => format!("{url}?token={token}"),
+            None => token.to_string(),
+        }
+    }
+
+    /// 激活账号, 已经激活过的账号同样视为成功
+    async fn activate(&self, user_id: &str) -> Result<()> {
+        let activated = self.repo.activate(user_id).await.map_err(|err| {
+            tracing::error!("activate user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+        if activated {
+            tracing::info!("user {} verified", user_id);
+            return Ok(());
+        }
+        let user = self.repo.find_by_user_id(user_id).await.map_err(|err| {
+            tracing::error!("activate user {} failed, {err:#}", user_id);
+            Error::InternalServerError
+        })?;
+        match user {
+            Some(u) if u.status == UserStatus::Active => Ok(()),
+            // 超时未验证的账号已被清理
+            _ => Err(Error::UserNotRegistered),
+        }
+    }
+}
+
+#[async_trait]
+impl IVerificationService for VerificationServiceImpl {
+    async fn send_email_link(&self, user: &Model) -> Result<()> {
+        let Some(email) = user.email.as_deref() else {
+            return Ok(());
+        };
+        let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let ttl = self.ttl();
+        let token_key = format!("{EMAIL_TOKEN_KEY_PREFIX}{}", hash_token(&token));
+        let redis_cli = self.redis_cli.get_conn();
+        redis_cli
+            .set::<(), _, _>(
+                &token_key,
+                user.user_id.as_str(),
+                Some(Expiration::EX(ttl.as_secs().max(1) as i64)),
+                None,
+                false,
+            )
+            .await
+            .map_err(|err| {
+                tracing::error!("save verification token of user {} failed, {err:#}", user.user_id);
+                Error::InternalServerError
+            })?;
+
+        let body = format!(
+            "{}, 您好:\n\n欢迎注册LeChat, 请在{}小时内打开以下链接验证邮箱, 逾期未验证的账号将被删除:\n\n{}\n\n如果不是您本人操作, 请忽略本邮件.",
+            user.nick_name,
+            ttl.as_secs().div_ceil(3600),
+            self.verify_link(&token)
+        );
+        if let Err(err) = self.mailer.send(email, "LeChat 邮箱验证", &body).await {
+            let _ = redis_cli.del::<(), _>(token_key).await;
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    async fn verify_email(&self, token: &str) -> Result<()> {
+        let user_id: Option<String> = self
+            .redis_cli
+            .get_conn()
+            .getdel(format!("{EMAIL_TOKEN_KEY_PREFIX}{}", hash_token(token)))
+            .await
+            .map_err(|err| {
+                tracing::error!("consume verification token failed, {err:#}");
+                Error::InternalServerError
+            })?;
+        let user_id = user_id.ok_or(Error::TokenInvalid)?;
+        self.activate(&user_id).await
+    }
+
+    async fn verify_mobile(&self, mobile: &str, code: &str) -> Result<()> {
+        self.sms_code.verify_code(mobile, code).await?;
+        let user = self.repo.find_by_phone(mobile).await.map_err(|err| {
+            tracing::error!("verify_mobile failed, {err:#}");
+            Error::InternalServerError
+        })?;
+        let u = user.ok_or(Error::UserNotRegistered)?;
+        self.activate(&u.user_id).await
+    }
+
+    async fn purge_unverified(&self) -> Result<u64> {
+        let ttl = chrono::Duration::from_std(self.ttl()).map_err(|err| {
+            tracing::error!("invalid verification ttl, {err:#}");
+            Error::InternalServerError
+        })?;
+        let registered_before = utils::time::now_naive_datetime() - ttl;
+        self.repo.delete_unverified(registered_before).await.map_err(|err| {
+            tracing::error!("purge unverified users failed, {err:#}");
+            Error::InternalServerError
+        })
+    }
+}
+
+/// 定期删除超时未验证的账号
+pub async fn purge_unverified_users(verification: Arc<dyn IVerificationService>, cfg: Arc<Config>) {
+    let mut ticker =
+        tokio::time::interval(cfg.verification.cleanup_interval.unwrap_or(DEFAULT_CLEANUP_INTERVAL));
+    loop {
+        ticker.tick().await;
+        match verification.purge_unverified().await {
+            Ok(0) => {}
+            Ok(count) => tracing::info!("purge {} unverified users", count),
+            Err(err) => tracing::error!("purge unverified users failed, {err:#}"),
+        }
+    }
+}